@@ -0,0 +1,488 @@
+use std::f64::consts::TAU;
+
+use fraction::GenericFraction;
+
+use crate::{GenericNumber,FractionInteger,GenericSymbolic};
+use crate::number::{is_exact,to_integer};
+use crate::special::is_zero;
+
+/// The unit an angle is measured in.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,Default)]
+pub enum AngleUnit {
+    /// A full turn is `2π` radians.
+    #[default]
+    Radians,
+    /// A full turn is 360 degrees.
+    Degrees,
+    /// A full turn is 400 gradians.
+    Gradians,
+    /// A full turn is one turn.
+    Turns
+}
+
+impl AngleUnit {
+    /// Returns the number of units in a full turn, or `None` for radians as `2π` is irrational.
+    fn per_turn(self) -> Option<i128> {
+        match self {
+            AngleUnit::Radians => None,
+            AngleUnit::Degrees => Some(360),
+            AngleUnit::Gradians => Some(400),
+            AngleUnit::Turns => Some(1)
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            AngleUnit::Radians => " rad",
+            AngleUnit::Degrees => "°",
+            AngleUnit::Gradians => " grad",
+            AngleUnit::Turns => " turn"
+        }
+    }
+}
+
+/// An angle measured in an `AngleUnit`.
+///
+/// The trigonometric functions of angles in degrees, gradians and turns are exact at the multiples of 30 and 45 degrees
+/// where their value is rational, so the sine of 30 degrees is `1/2` and the tangent of 45 degrees is `1`. Their other values,
+/// and those of every angle in radians apart from zero, are decimals. An angle made from a multiple of `π` as a `Symbolic` is held in turns,
+/// so the sine of `π/6` is exactly `1/2` as well. The inverse functions, such as `asin`, likewise give exact angles
+/// for these values in every unit but radians.
+///
+/// The tangent, secant, cosecant and cotangent are infinite at their poles, as with division by zero.
+#[derive(Debug,Clone)]
+pub struct GenericAngle<T : FractionInteger> {
+    value : GenericNumber<T>,
+    unit : AngleUnit
+}
+
+/// An angle whose fractions have a `u32` numerator and denominator.
+pub type Angle = GenericAngle<u32>;
+
+impl<T : FractionInteger> GenericAngle<T> {
+    /// Creates an angle of `value` in `unit`.
+    pub fn new(value : GenericNumber<T>,unit : AngleUnit) -> Self {
+        Self { value , unit }
+    }
+
+    /// Creates an angle in radians.
+    pub fn radians(value : GenericNumber<T>) -> Self {
+        Self::new(value,AngleUnit::Radians)
+    }
+
+    /// Creates an angle in degrees.
+    pub fn degrees(value : GenericNumber<T>) -> Self {
+        Self::new(value,AngleUnit::Degrees)
+    }
+
+    /// Creates an angle in gradians.
+    pub fn gradians(value : GenericNumber<T>) -> Self {
+        Self::new(value,AngleUnit::Gradians)
+    }
+
+    /// Creates an angle in turns.
+    pub fn turns(value : GenericNumber<T>) -> Self {
+        Self::new(value,AngleUnit::Turns)
+    }
+
+    /// Retrieves the size of the angle in its unit.
+    pub fn value(&self) -> &GenericNumber<T> {
+        &self.value
+    }
+
+    /// Retrieves the unit of the angle.
+    pub fn unit(&self) -> AngleUnit {
+        self.unit
+    }
+
+    /// Converts the angle into `unit`, which is exact between degrees, gradians and turns.
+    pub fn to_unit(&self,unit : AngleUnit) -> Self {
+        let value = match (self.unit.per_turn(),unit.per_turn()) {
+            _ if self.unit == unit => self.value.clone(),
+            (Some(from),Some(to)) => self.value.clone() * GenericNumber::Integer(to) / GenericNumber::Integer(from),
+            (Some(from),None) => GenericNumber::Decimal(f64::from(self.value.clone()) / from as f64 * TAU),
+            (None,Some(to)) => GenericNumber::Decimal(f64::from(self.value.clone()) / TAU * to as f64),
+            (None,None) => unreachable!("radians are the only unit without a whole number of units in a turn")
+        };
+
+        Self::new(value,unit)
+    }
+
+    /// Computes the sine of the angle.
+    pub fn sin(&self) -> GenericNumber<T> {
+        self.special_index().and_then(sine).unwrap_or_else(|| GenericNumber::Decimal(self.float_radians().sin()))
+    }
+
+    /// Computes the cosine of the angle.
+    pub fn cos(&self) -> GenericNumber<T> {
+        self.special_index().and_then(cosine).unwrap_or_else(|| GenericNumber::Decimal(self.float_radians().cos()))
+    }
+
+    /// Computes the tangent of the angle.
+    pub fn tan(&self) -> GenericNumber<T> {
+        self.special_index().and_then(tangent).unwrap_or_else(|| GenericNumber::Decimal(self.float_radians().tan()))
+    }
+
+    /// Computes the secant of the angle, which is one over its cosine.
+    pub fn sec(&self) -> GenericNumber<T> {
+        self.special_index().and_then(cosine).map(reciprocal).unwrap_or_else(|| GenericNumber::Decimal(self.float_radians().cos().recip()))
+    }
+
+    /// Computes the cosecant of the angle, which is one over its sine.
+    pub fn csc(&self) -> GenericNumber<T> {
+        self.special_index().and_then(sine).map(reciprocal).unwrap_or_else(|| GenericNumber::Decimal(self.float_radians().sin().recip()))
+    }
+
+    /// Computes the cotangent of the angle, which is one over its tangent.
+    pub fn cot(&self) -> GenericNumber<T> {
+        self.special_index().and_then(tangent).map(reciprocal).unwrap_or_else(|| GenericNumber::Decimal(self.float_radians().tan().recip()))
+    }
+
+    /// Computes the angle in `unit` whose sine is `ratio`, between -90 and 90 degrees.
+    pub fn asin(ratio : &GenericNumber<T>,unit : AngleUnit) -> Self {
+        Self::inverse(ratio,unit,&[(0,1,0),(1,2,30),(1,1,90),(-1,2,-30),(-1,1,-90)],f64::asin)
+    }
+
+    /// Computes the angle in `unit` whose cosine is `ratio`, between 0 and 180 degrees.
+    pub fn acos(ratio : &GenericNumber<T>,unit : AngleUnit) -> Self {
+        Self::inverse(ratio,unit,&[(1,1,0),(1,2,60),(0,1,90),(-1,2,120),(-1,1,180)],f64::acos)
+    }
+
+    /// Computes the angle in `unit` whose tangent is `ratio`, between -90 and 90 degrees.
+    pub fn atan(ratio : &GenericNumber<T>,unit : AngleUnit) -> Self {
+        Self::inverse(ratio,unit,&ARCTANGENTS,f64::atan)
+    }
+
+    /// Computes the angle in `unit` whose secant is `ratio`, between 0 and 180 degrees.
+    pub fn asec(ratio : &GenericNumber<T>,unit : AngleUnit) -> Self {
+        Self::acos(&reciprocal(ratio.clone()),unit)
+    }
+
+    /// Computes the angle in `unit` whose cosecant is `ratio`, between -90 and 90 degrees.
+    pub fn acsc(ratio : &GenericNumber<T>,unit : AngleUnit) -> Self {
+        Self::asin(&reciprocal(ratio.clone()),unit)
+    }
+
+    /// Computes the angle in `unit` whose cotangent is `ratio`, between -90 and 90 degrees.
+    pub fn acot(ratio : &GenericNumber<T>,unit : AngleUnit) -> Self {
+        Self::atan(&reciprocal(ratio.clone()),unit)
+    }
+
+    /// Computes the angle in `unit` from the positive x-axis to the point `(x, y)`, between -180 and 180 degrees.
+    ///
+    /// Like `f64::atan2`, the signs of zeros are kept, so a `y` of `-0.0` with a negative `x` gives -180 degrees.
+    pub fn atan2(y : &GenericNumber<T>,x : &GenericNumber<T>,unit : AngleUnit) -> Self {
+        let zero = GenericNumber::Integer(0);
+        let degrees = match is_zero(x) {
+            true if *y > zero => Some(90),
+            true if *y < zero => Some(-90),
+            true => Some(match (is_sign_negative(y),is_sign_negative(x)) {
+                (_,false) => 0,
+                (false,true) => 180,
+                (true,true) => -180
+            }),
+            false => special_degrees(&(y.clone() / x.clone()),&ARCTANGENTS).map(|degrees| match *x > zero {
+                true => degrees,
+                false if !is_sign_negative(y) => degrees + 180,
+                false => degrees - 180
+            })
+        };
+
+        match degrees {
+            Some(degrees) if unit != AngleUnit::Radians => Self::degrees(GenericNumber::Integer(degrees)).to_unit(unit),
+            _ => Self::radians(GenericNumber::Decimal(f64::from(y.clone()).atan2(f64::from(x.clone())))).to_unit(unit)
+        }
+    }
+
+    fn inverse(ratio : &GenericNumber<T>,unit : AngleUnit,table : &[(i128,i128,i128)],function : fn(f64) -> f64) -> Self {
+        match special_degrees(ratio,table) {
+            Some(degrees) if unit != AngleUnit::Radians => Self::degrees(GenericNumber::Integer(degrees)).to_unit(unit),
+            _ => Self::radians(GenericNumber::Decimal(function(f64::from(ratio.clone())))).to_unit(unit)
+        }
+    }
+
+    /// Returns the angle as a multiple of 15 degrees between 0 and 23, or `None` if it is not such a multiple.
+    fn special_index(&self) -> Option<i128> {
+        // zero is the only special angle that is a whole number of radians
+        if is_exact(&self.value) && is_zero(&self.value) {
+            return Some(0);
+        }
+
+        let per_turn = self.unit.per_turn()?;
+        let index = to_integer(&(self.value.clone() * GenericNumber::Integer(24) / GenericNumber::Integer(per_turn)))?;
+
+        // the comparison is exact, so decimals that are only close to a special angle are left out
+        let special = GenericNumber::<T>::Integer(index) * GenericNumber::Integer(per_turn) / GenericNumber::Integer(24);
+        (is_exact(&special) && special == self.value).then_some(index.rem_euclid(24))
+    }
+
+    /// Converts the angle into radians, taking whole turns off first so large angles stay precise.
+    fn float_radians(&self) -> f64 {
+        match self.unit.per_turn() {
+            Some(per_turn) => f64::from(self.value.clone() % GenericNumber::Integer(per_turn)) / per_turn as f64 * TAU,
+            None => f64::from(self.value.clone())
+        }
+    }
+}
+
+/// Returns `true` if the number is negative or a negative zero, which only decimals and numbers in standard form can hold.
+fn is_sign_negative<T : FractionInteger>(number : &GenericNumber<T>) -> bool {
+    match number {
+        GenericNumber::Decimal(d) => d.is_sign_negative(),
+        GenericNumber::StandardForm(sf) => sf.mantissa().is_sign_negative(),
+        number => *number < GenericNumber::Integer(0)
+    }
+}
+
+/// The ratios `numer/denom` whose arctangent is a whole number of degrees.
+const ARCTANGENTS : [(i128,i128,i128);3] = [(0,1,0),(1,1,45),(-1,1,-45)];
+
+/// Finds the angle in degrees that a table of inverse values gives for `ratio`.
+fn special_degrees<T : FractionInteger>(ratio : &GenericNumber<T>,table : &[(i128,i128,i128)]) -> Option<i128> {
+    table.iter()
+        .find(|(numer,denom,_)| ratio.clone() * GenericNumber::Integer(*denom) == GenericNumber::Integer(*numer))
+        .map(|(_,_,degrees)| *degrees)
+}
+
+/// Returns the sine of `index * 15` degrees if it is rational.
+fn sine<T : FractionInteger>(index : i128) -> Option<GenericNumber<T>> {
+    match index.rem_euclid(24) {
+        0 | 12 => Some(GenericNumber::Integer(0)),
+        6 => Some(GenericNumber::Integer(1)),
+        18 => Some(GenericNumber::Integer(-1)),
+        2 | 10 => Some(GenericNumber::Fraction(GenericFraction::new(T::one(),T::from_u8(2)?))),
+        14 | 22 => Some(GenericNumber::Fraction(GenericFraction::new_neg(T::one(),T::from_u8(2)?))),
+        _ => None
+    }
+}
+
+/// Returns the cosine of `index * 15` degrees if it is rational.
+fn cosine<T : FractionInteger>(index : i128) -> Option<GenericNumber<T>> {
+    sine(index + 6)
+}
+
+/// Returns the tangent of `index * 15` degrees if it is rational.
+fn tangent<T : FractionInteger>(index : i128) -> Option<GenericNumber<T>> {
+    match index.rem_euclid(12) {
+        0 => Some(GenericNumber::Integer(0)),
+        3 => Some(GenericNumber::Integer(1)),
+        9 => Some(GenericNumber::Integer(-1)),
+        // the poles, where the cosine is zero
+        6 => Some(sine::<T>(index)? / cosine(index)?),
+        _ => None
+    }
+}
+
+/// Divides one by the number, giving an integer when the reciprocal of a fraction is whole, so the secant of 60 degrees is `2`.
+fn reciprocal<T : FractionInteger>(number : GenericNumber<T>) -> GenericNumber<T> {
+    match GenericNumber::Integer(1) / number {
+        GenericNumber::Fraction(fr) if fr.denom() == Some(&T::one()) => to_integer(&GenericNumber::Fraction(fr.clone())).map_or(GenericNumber::Fraction(fr),GenericNumber::Integer),
+        result => result
+    }
+}
+
+/// The trigonometric functions of an angle take either a number, which is an angle in radians, or an `Angle` in any unit,
+/// so `Number::sin(Angle::degrees(Number::Integer(30)))` is exactly `1/2`.
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Computes the sine of an angle.
+    pub fn sin(angle : impl Into<GenericAngle<T>>) -> GenericNumber<T> {
+        angle.into().sin()
+    }
+
+    /// Computes the cosine of an angle.
+    pub fn cos(angle : impl Into<GenericAngle<T>>) -> GenericNumber<T> {
+        angle.into().cos()
+    }
+
+    /// Computes the tangent of an angle.
+    pub fn tan(angle : impl Into<GenericAngle<T>>) -> GenericNumber<T> {
+        angle.into().tan()
+    }
+
+    /// Computes the secant of an angle.
+    pub fn sec(angle : impl Into<GenericAngle<T>>) -> GenericNumber<T> {
+        angle.into().sec()
+    }
+
+    /// Computes the cosecant of an angle.
+    pub fn csc(angle : impl Into<GenericAngle<T>>) -> GenericNumber<T> {
+        angle.into().csc()
+    }
+
+    /// Computes the cotangent of an angle.
+    pub fn cot(angle : impl Into<GenericAngle<T>>) -> GenericNumber<T> {
+        angle.into().cot()
+    }
+
+    /// Computes the arcsecant of a number.
+    pub fn asec<O : From<f64>>(self) -> O {
+        f64::from(self).recip().acos().into()
+    }
+
+    /// Computes the arccosecant of a number.
+    pub fn acsc<O : From<f64>>(self) -> O {
+        f64::from(self).recip().asin().into()
+    }
+
+    /// Computes the arccotangent of a number.
+    pub fn acot<O : From<f64>>(self) -> O {
+        f64::from(self).recip().atan().into()
+    }
+
+    /// Computes the angle (in radians) from the positive x-axis to the point `(x, self)`.
+    pub fn atan2<O : From<f64>>(self,x : Self) -> O {
+        f64::from(self).atan2(f64::from(x)).into()
+    }
+
+    /// Computes the length of the hypotenuse of a right-angled triangle with sides of the number and `other`.
+    ///
+    /// The result is exact when both sides are integers or fractions and their squares sum to a perfect square.
+    pub fn hypot(&self,other : &Self) -> GenericNumber<T> {
+        match is_exact(self) && is_exact(other) {
+            true => (self.clone() * self.clone() + other.clone() * other.clone()).sqrt(),
+            false => GenericNumber::Decimal(f64::from(self.clone()).hypot(f64::from(other.clone())))
+        }
+    }
+}
+
+/// A number is an angle in radians, apart from multiples of `π`, which are held in turns like a `Symbolic`.
+impl<T : FractionInteger> From<GenericNumber<T>> for GenericAngle<T> {
+    fn from(value: GenericNumber<T>) -> Self {
+        match value {
+            GenericNumber::Symbolic(symbolic) => Self::from(*symbolic),
+            value => Self::radians(value)
+        }
+    }
+}
+
+/// A multiple of `π` is an exact angle, held in turns as a full turn is `2π` radians.
+/// Other symbolic numbers are evaluated into radians.
+impl<T : FractionInteger> From<GenericSymbolic<T>> for GenericAngle<T> {
+    fn from(value: GenericSymbolic<T>) -> Self {
+        match (value.pi_power(),value.e_power()) {
+            (1,0) => Self::turns(value.coefficient().clone() / GenericNumber::Integer(2)),
+            (0,0) => Self::radians(value.coefficient().clone()),
+            _ => Self::radians(GenericNumber::Decimal(value.to_f64()))
+        }
+    }
+}
+
+impl<T : FractionInteger> std::fmt::Display for GenericAngle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f,"{}{}",self.value,self.unit.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2,FRAC_PI_6,SQRT_2};
+
+    use crate::{Number,Symbolic};
+
+    use super::*;
+
+    fn degrees(value : i128) -> Angle {
+        Angle::degrees(Number::Integer(value))
+    }
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_special_values() {
+        let half = fraction(1,2);
+        assert!(degrees(30).sin().structural_eq(&half));
+        assert!(degrees(150).sin().structural_eq(&half));
+        assert!(degrees(390).sin().structural_eq(&half));
+        assert!(degrees(-30).sin().structural_eq(&-half.clone()));
+        assert!(degrees(60).cos().structural_eq(&half));
+        assert!(degrees(90).cos().structural_eq(&Number::Integer(0)));
+        assert!(degrees(180).cos().structural_eq(&Number::Integer(-1)));
+
+        assert!(degrees(45).tan().structural_eq(&Number::Integer(1)));
+        assert!(degrees(135).tan().structural_eq(&Number::Integer(-1)));
+        assert_eq!(degrees(90).tan(),f64::INFINITY);
+
+        assert!(degrees(60).sec().structural_eq(&Number::Integer(2)));
+        assert!(degrees(30).csc().structural_eq(&Number::Integer(2)));
+        assert!(degrees(45).cot().structural_eq(&Number::Integer(1)));
+
+        assert!(Angle::gradians(Number::Integer(50)).tan().structural_eq(&Number::Integer(1)));
+        assert!(Angle::turns(fraction(1,4)).sin().structural_eq(&Number::Integer(1)));
+        assert!(Angle::degrees(Number::Decimal(30.0)).sin().structural_eq(&half));
+    }
+
+    #[test]
+    fn test_other_values() {
+        assert!(degrees(45).sin().structural_eq(&Number::Decimal((TAU / 8.0).sin())));
+        assert!((f64::from(degrees(45).sin()) - SQRT_2 / 2.0).abs() < 1e-15);
+        assert!(Angle::radians(Number::Decimal(FRAC_PI_6)).sin().structural_eq(&Number::Decimal(FRAC_PI_6.sin())));
+
+        // whole turns are taken off exactly before converting into radians
+        assert_eq!(degrees(360 * 1_000_000_000 + 1).sin(),degrees(1).sin());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert!(degrees(90).to_unit(AngleUnit::Turns).value().structural_eq(&fraction(1,4)));
+        assert!(degrees(90).to_unit(AngleUnit::Gradians).value().structural_eq(&Number::Integer(100)));
+        assert_eq!(*degrees(90).to_unit(AngleUnit::Radians).value(),FRAC_PI_2);
+        assert_eq!(*Angle::radians(Number::Decimal(FRAC_PI_2)).to_unit(AngleUnit::Degrees).value(),90.0);
+
+        assert_eq!(degrees(30).to_string(),"30°");
+        assert_eq!(Angle::turns(fraction(1,4)).to_string(),"1/4 turn");
+    }
+
+    #[test]
+    fn test_inverse_functions() {
+        assert!(Angle::asin(&fraction(1,2),AngleUnit::Degrees).value().structural_eq(&Number::Integer(30)));
+        assert!(Angle::acos(&-fraction(1,2),AngleUnit::Degrees).value().structural_eq(&Number::Integer(120)));
+        assert!(Angle::atan(&Number::Integer(1),AngleUnit::Turns).value().structural_eq(&fraction(1,8)));
+        assert!(Angle::asec(&Number::Integer(2),AngleUnit::Degrees).value().structural_eq(&Number::Integer(60)));
+        assert!(Angle::acsc(&Number::Integer(-1),AngleUnit::Degrees).value().structural_eq(&Number::Integer(-90)));
+        assert_eq!(*Angle::acot(&Number::Integer(0),AngleUnit::Degrees).value(),90.0);
+        assert_eq!(*Angle::asin(&fraction(1,2),AngleUnit::Radians).value(),FRAC_PI_6);
+
+        assert!(Angle::atan2(&Number::Integer(-1),&Number::Integer(-1),AngleUnit::Degrees).value().structural_eq(&Number::Integer(-135)));
+        assert!(Angle::atan2(&Number::Integer(1),&Number::Integer(0),AngleUnit::Turns).value().structural_eq(&fraction(1,4)));
+        assert!(Angle::atan2(&Number::Integer(0),&Number::Integer(-3),AngleUnit::Degrees).value().structural_eq(&Number::Integer(180)));
+        assert!(Angle::atan2(&Number::Decimal(-0.0),&Number::Integer(-3),AngleUnit::Degrees).value().structural_eq(&Number::Integer(-180)));
+        assert!(Angle::atan2(&Number::Decimal(-0.0),&Number::Decimal(-0.0),AngleUnit::Degrees).value().structural_eq(&Number::Integer(-180)));
+        assert_eq!(*Angle::atan2(&Number::Decimal(-0.0),&Number::Integer(-3),AngleUnit::Radians).value(),-std::f64::consts::PI);
+    }
+
+    #[test]
+    fn test_multiples_of_pi() {
+        let sixth = Angle::from(Symbolic::pi() / Number::Integer(6));
+        assert!(sixth.value().structural_eq(&fraction(1,12)));
+        assert!(sixth.sin().structural_eq(&fraction(1,2)));
+        assert!(Angle::from(Symbolic::try_from("2π/3").unwrap()).cos().structural_eq(&-fraction(1,2)));
+        assert!(Angle::from(Symbolic::pi()).cos().structural_eq(&Number::Integer(-1)));
+        assert!(Angle::from(Symbolic::pi() / Number::Integer(4)).tan().structural_eq(&Number::Integer(1)));
+
+        assert!(Angle::from(Symbolic::from(Number::Integer(1))).sin().structural_eq(&Number::Decimal((1.0_f64).sin())));
+        assert!(Angle::from(Symbolic::e()).sin().structural_eq(&Number::Decimal(std::f64::consts::E.sin())));
+    }
+
+    #[test]
+    fn test_number_functions() {
+        assert!(Number::Integer(3).hypot(&Number::Integer(4)).structural_eq(&Number::Integer(5)));
+        assert!(Number::Decimal(1.0).hypot(&Number::Decimal(1.0)).structural_eq(&Number::Decimal(SQRT_2)));
+        assert_eq!(Number::Integer(1).atan2::<f64>(Number::Integer(1)),std::f64::consts::FRAC_PI_4);
+        assert_eq!(Number::Integer(2).asec::<f64>(),(0.5_f64).acos());
+    }
+
+    #[test]
+    fn test_number_functions_take_angles() {
+        assert!(Number::sin(degrees(30)).structural_eq(&fraction(1,2)));
+        assert!(Number::cos(Angle::turns(fraction(1,2))).structural_eq(&Number::Integer(-1)));
+        assert!(Number::tan(Symbolic::pi() / Number::Integer(4)).structural_eq(&Number::Integer(1)));
+        assert!(Number::sec(Number::Integer(0)).structural_eq(&Number::Integer(1)));
+        assert!(Number::csc(degrees(30)).structural_eq(&Number::Integer(2)));
+        assert!(Number::cot(degrees(45)).structural_eq(&Number::Integer(1)));
+
+        // a number is an angle in radians
+        assert!(Number::sin(Number::Decimal(FRAC_PI_6)).structural_eq(&Number::Decimal(FRAC_PI_6.sin())));
+    }
+}
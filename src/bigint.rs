@@ -0,0 +1,442 @@
+use std::cmp::Ordering;
+
+use fraction::{BigFraction, BigUint, GenericFraction, Integer, One, Ratio, Sign, ToPrimitive, Zero};
+use fraction::generic::read_generic_integer;
+use standardform::StandardForm;
+
+use crate::{GenericNumber,FractionInteger,RoundingMode};
+use crate::cmp::float_parts;
+use crate::number::{digits_to_sf, ratio_to_f64, ratio_to_sf, sf_decimal};
+use crate::round::Dropped;
+
+/// Converts a fraction into a big fraction holding the same value.
+pub(crate) fn widen<T : FractionInteger>(fr : &GenericFraction<T>) -> BigFraction {
+    match fr {
+        GenericFraction::Rational(sign,ratio) => {
+            // signed integers may carry the sign of the fraction in its numerator or denominator
+            let (numer_sign,numer) = to_big_uint(ratio.numer());
+            let (denom_sign,denom) = to_big_uint(ratio.denom());
+            GenericFraction::Rational(*sign * numer_sign * denom_sign,Ratio::new_raw(numer,denom))
+        },
+        GenericFraction::Infinity(sign) => GenericFraction::Infinity(*sign),
+        GenericFraction::NaN => GenericFraction::NaN,
+    }
+}
+
+/// Splits an integer into its sign and magnitude.
+fn to_big_uint<T : FractionInteger>(n : &T) -> (Sign,BigUint) {
+    match n.to_u128() {
+        Some(n) => (Sign::Plus,BigUint::from(n)),
+        None => read_generic_integer(n.clone()).expect("every integer fits into a big integer")
+    }
+}
+
+/// Holds a big fraction in `Number::Fraction` if its numerator and denominator fit into `T`,
+/// and in `Number::BigFraction` otherwise.
+pub(crate) fn narrow<T : FractionInteger>(fr : BigFraction) -> GenericNumber<T> {
+    let from_big_uint = |n : &BigUint| n.to_u128().and_then(T::from_u128);
+
+    match &fr {
+        GenericFraction::Rational(sign,ratio) => match (from_big_uint(ratio.numer()),from_big_uint(ratio.denom())) {
+            (Some(numer),Some(denom)) => GenericNumber::Fraction(GenericFraction::Rational(*sign,Ratio::new_raw(numer,denom))),
+            _ => GenericNumber::BigFraction(fr)
+        },
+        GenericFraction::Infinity(sign) => GenericNumber::Fraction(GenericFraction::Infinity(*sign)),
+        GenericFraction::NaN => GenericNumber::Fraction(GenericFraction::NaN),
+    }
+}
+
+/// Converts an integer into a big fraction holding the same value.
+pub(crate) fn integer_to_big_fraction(integer : i128) -> BigFraction {
+    let sign = match integer.is_negative() {
+        true => Sign::Minus,
+        false => Sign::Plus
+    };
+
+    GenericFraction::Rational(sign,Ratio::from_integer(BigUint::from(integer.unsigned_abs())))
+}
+
+/// Raises an integer to a power exactly, for powers whose result does not fit into an `i128`.
+pub(crate) fn big_integer_pow<T : FractionInteger>(base : i128,power : u32) -> GenericNumber<T> {
+    let sign = match base.is_negative() && power % 2 == 1 {
+        true => Sign::Minus,
+        false => Sign::Plus
+    };
+
+    narrow(GenericFraction::Rational(sign,Ratio::from_integer(BigUint::from(base.unsigned_abs()).pow(power))))
+}
+
+/// Raises a big fraction to a power exactly, returning `None` if it is NaN or an infinity.
+pub(crate) fn big_fraction_pow(fr : &BigFraction,power : u32) -> Option<BigFraction> {
+    let GenericFraction::Rational(sign,ratio) = fr else {
+        return None;
+    };
+
+    let sign = match power % 2 {
+        0 => Sign::Plus,
+        _ => *sign
+    };
+
+    Some(GenericFraction::Rational(sign,Ratio::new_raw(ratio.numer().pow(power),ratio.denom().pow(power))))
+}
+
+/// Converts a number into a big fraction, returning `None` if a decimal has no exact fraction.
+///
+/// Decimals and numbers in standard form are read as the decimals they are written as, so `0.1` becomes `1/10`.
+pub(crate) fn to_big_fraction<T : FractionInteger>(number : &GenericNumber<T>) -> Option<BigFraction> {
+    match number {
+        GenericNumber::Integer(i) => Some(integer_to_big_fraction(*i)),
+        GenericNumber::Fraction(fr) => Some(widen(fr)),
+        GenericNumber::BigFraction(fr) => Some(fr.clone()),
+        GenericNumber::Symbolic(_) => None,
+        GenericNumber::Decimal(d) => {
+            let fraction = BigFraction::from(*d);
+            (d.is_nan() || big_fraction_to_f64(&fraction) == *d).then_some(fraction)
+        },
+        GenericNumber::StandardForm(sf) if !sf.mantissa().is_finite() || *sf.mantissa() == 0.0 => Some(BigFraction::from(*sf.mantissa())),
+        GenericNumber::StandardForm(sf) => {
+            let (significand,power) = sf_decimal(sf);
+            let scale = BigUint::from(10u8).pow(power.unsigned_abs());
+            let ratio = match power >= 0 {
+                true => Ratio::from_integer(BigUint::from(significand) * scale),
+                false => Ratio::new(BigUint::from(significand),scale)
+            };
+
+            Some(match sf.mantissa().is_sign_negative() {
+                true => GenericFraction::Rational(Sign::Minus,ratio),
+                false => GenericFraction::Rational(Sign::Plus,ratio)
+            })
+        }
+    }
+}
+
+/// Converts a big fraction into the nearest `f64`, rounding ties to even.
+pub(crate) fn big_fraction_to_f64(fr : &BigFraction) -> f64 {
+    match fr {
+        GenericFraction::Rational(sign,ratio) => {
+            let float = big_ratio_to_f64(ratio.numer(),ratio.denom());
+            match sign.is_positive() {
+                true => float,
+                false => -float
+            }
+        },
+        GenericFraction::Infinity(sign) => match sign.is_positive() {
+            true => f64::INFINITY,
+            false => -f64::INFINITY
+        },
+        GenericFraction::NaN => f64::NAN,
+    }
+}
+
+/// Divides two big integers, rounding the quotient to the nearest `f64` with ties to even.
+fn big_ratio_to_f64(numer : &BigUint,denom : &BigUint) -> f64 {
+    if let (Some(numer),Some(denom)) = (numer.to_u128(),denom.to_u128()) {
+        return ratio_to_f64(numer,denom);
+    }
+
+    // scales the quotient to between 2^65 and 2^67, leaving enough bits below the mantissa to round correctly
+    let shift = numer.bits() as i64 - denom.bits() as i64 - 66;
+    let (quotient,remainder) = match shift >= 0 {
+        true => numer.div_rem(&(denom << shift as usize)),
+        false => (numer << shift.unsigned_abs() as usize).div_rem(denom)
+    };
+
+    // any remainder only breaks ties, so it can be folded into the lowest bit
+    let quotient = quotient.to_u128().expect("the quotient has at most 67 bits") | !remainder.is_zero() as u128;
+
+    // scales back in two steps, as the power of two alone may overflow or underflow
+    let shift = shift.clamp(-4096,4096) as i32;
+    quotient as f64 * 2_f64.powi(shift / 2) * 2_f64.powi(shift - shift / 2)
+}
+
+/// Converts a finite big fraction into standard form.
+///
+/// Non-terminating decimals such as `1/3` are rounded to the nearest mantissa an `f64` can hold.
+pub(crate) fn big_fraction_to_sf(fr : &BigFraction) -> StandardForm {
+    let GenericFraction::Rational(sign,ratio) = fr else {
+        unreachable!("standard form cannot hold NaN or infinities");
+    };
+
+    let sf = big_ratio_to_sf(ratio.numer(),ratio.denom());
+    match sign {
+        Sign::Plus => sf,
+        Sign::Minus => -sf
+    }
+}
+
+fn big_ratio_to_sf(numer : &BigUint,denom : &BigUint) -> StandardForm {
+    // more digits than an `f64` can distinguish, so parsing them rounds the mantissa correctly
+    const SIGNIFICANT_DIGITS : i32 = 21;
+
+    if let (Some(numer),Some(denom)) = (numer.to_u128(),denom.to_u128()) {
+        return ratio_to_sf(numer,denom);
+    }
+
+    // scales the quotient to between 10^20 and 10^22
+    let magnitude = numer.to_string().len() as i32 - denom.to_string().len() as i32;
+    let shift = SIGNIFICANT_DIGITS - magnitude;
+    let ten = BigUint::from(10u8);
+    let (quotient,remainder) = match shift >= 0 {
+        true => (numer * ten.pow(shift.unsigned_abs())).div_rem(denom),
+        false => numer.div_rem(&(denom * ten.pow(shift.unsigned_abs())))
+    };
+
+    let mut digits = quotient.to_string();
+    let exponent = digits.len() as i32 - 1 - shift;
+
+    // digits left over only break ties, so a trailing one stands in for them
+    if !remainder.is_zero() {
+        digits.push('1');
+    }
+
+    digits_to_sf(&digits,exponent)
+}
+
+/// Rounds a big fraction to `places` decimal places, leaving NaN and infinities as they are.
+pub(crate) fn round_big_fraction(fr : &BigFraction,places : i32,mode : RoundingMode) -> BigFraction {
+    let GenericFraction::Rational(sign,ratio) = fr else {
+        return fr.clone();
+    };
+
+    let scale = BigUint::from(10u8).pow(places.unsigned_abs());
+    let (numer,denom) = match places >= 0 {
+        true => (ratio.numer() * &scale,ratio.denom().clone()),
+        false => (ratio.numer().clone(),ratio.denom() * &scale)
+    };
+
+    let (kept,remainder) = numer.div_rem(&denom);
+    if remainder.is_zero() {
+        return fr.clone();
+    }
+
+    let dropped = Dropped::from_cmp(remainder.cmp(&(&denom - &remainder)));
+    let kept = match mode.rounds_away(sign.is_negative(),kept.is_odd(),dropped) {
+        true => kept + 1u8,
+        false => kept
+    };
+
+    GenericFraction::Rational(*sign,match places >= 0 {
+        true => Ratio::new(kept,scale),
+        false => Ratio::from_integer(kept * scale)
+    })
+}
+
+/// Takes the `degree`th root of a big fraction exactly, returning `None` if its numerator or denominator is not a perfect power
+/// or if it is negative and `degree` is even.
+pub(crate) fn big_fraction_root(fr : &BigFraction,degree : u32) -> Option<BigFraction> {
+    let GenericFraction::Rational(sign,ratio) = fr else {
+        return None;
+    };
+
+    if sign.is_negative() && degree.is_multiple_of(2) && !ratio.is_zero() {
+        return None;
+    }
+
+    let exact_root = |n : &BigUint| Some(n.nth_root(degree)).filter(|root| root.pow(degree) == *n);
+    Some(GenericFraction::Rational(*sign,Ratio::new_raw(exact_root(ratio.numer())?,exact_root(ratio.denom())?)))
+}
+
+/// Holds `±kept * 10^-places` exactly as a big fraction.
+pub(crate) fn scaled_big_fraction(negative : bool,kept : u128,places : i32) -> BigFraction {
+    let sign = match negative {
+        true => Sign::Minus,
+        false => Sign::Plus
+    };
+
+    let scale = BigUint::from(10u8).pow(places.unsigned_abs());
+    GenericFraction::Rational(sign,match places >= 0 {
+        true => Ratio::new(BigUint::from(kept),scale),
+        false => Ratio::from_integer(BigUint::from(kept) * scale)
+    })
+}
+
+/// Returns the exponent of the first significant digit of a big fraction, or `None` if it is zero, NaN or an infinity.
+pub(crate) fn big_fraction_exponent(fr : &BigFraction) -> Option<i32> {
+    let GenericFraction::Rational(_,ratio) = fr else {
+        return None;
+    };
+
+    if ratio.is_zero() {
+        return None;
+    }
+
+    // the quotient lies strictly between 10^(magnitude - 1) and 10^(magnitude + 1)
+    let magnitude = ratio.numer().to_string().len() as i32 - ratio.denom().to_string().len() as i32;
+    let scale = BigUint::from(10u8).pow(magnitude.unsigned_abs());
+    let below = match magnitude >= 0 {
+        true => *ratio.numer() < ratio.denom() * scale,
+        false => ratio.numer() * scale < *ratio.denom()
+    };
+
+    Some(magnitude - below as i32)
+}
+
+/// Splits a big fraction into a mantissa between one and ten and a power of ten, so values beyond the range of `f64`
+/// keep their magnitude. Returns `None` if it is zero, NaN or an infinity.
+pub(crate) fn big_fraction_decimal_parts(fr : &BigFraction) -> Option<(f64,i64)> {
+    let exponent = big_fraction_exponent(fr)?;
+    let GenericFraction::Rational(sign,ratio) = fr else {
+        return None;
+    };
+
+    let scale = BigUint::from(10u8).pow(exponent.unsigned_abs());
+    let mantissa = match exponent >= 0 {
+        true => big_ratio_to_f64(ratio.numer(),&(ratio.denom() * scale)),
+        false => big_ratio_to_f64(&(ratio.numer() * scale),ratio.denom())
+    };
+
+    match sign.is_negative() {
+        true => Some((-mantissa,exponent as i64)),
+        false => Some((mantissa,exponent as i64))
+    }
+}
+
+/// Compares the exact values of two numbers as big fractions, returning `None` if either of them is NaN.
+pub(crate) fn big_value_cmp<T : FractionInteger>(lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Option<Ordering> {
+    match (exact_value(lhs),exact_value(rhs)) {
+        (GenericFraction::NaN,_) | (_,GenericFraction::NaN) => None,
+        (lhs,rhs) => Some(lhs.cmp(&rhs))
+    }
+}
+
+/// Converts a number into a big fraction holding its exact value, reading floats as their exact binary value.
+fn exact_value<T : FractionInteger>(number : &GenericNumber<T>) -> BigFraction {
+    match number {
+        GenericNumber::Integer(i) => integer_to_big_fraction(*i),
+        GenericNumber::Fraction(fr) => widen(fr),
+        GenericNumber::BigFraction(fr) => fr.clone(),
+        other => float_to_big_fraction(f64::from(other.clone()))
+    }
+}
+
+fn float_to_big_fraction(float : f64) -> BigFraction {
+    let sign = match float.is_sign_negative() {
+        true => Sign::Minus,
+        false => Sign::Plus
+    };
+
+    if float.is_nan() {
+        return GenericFraction::NaN;
+    }
+
+    if float.is_infinite() {
+        return GenericFraction::Infinity(sign);
+    }
+
+    if float == 0.0 {
+        return GenericFraction::Rational(sign,Ratio::zero());
+    }
+
+    let (mantissa,exponent) = float_parts(float.abs());
+    let ratio = match exponent >= 0 {
+        true => Ratio::from_integer(BigUint::from(mantissa) << exponent as usize),
+        false => Ratio::new_raw(BigUint::from(mantissa),BigUint::one() << exponent.unsigned_abs() as usize)
+    };
+
+    GenericFraction::Rational(sign,ratio)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Number,PromotionPolicy};
+
+    use super::*;
+
+    fn two_to_the_64() -> Number {
+        Number::BigFraction(BigFraction::new(BigUint::one() << 64usize,BigUint::one()))
+    }
+
+    #[test]
+    fn test_fraction_arithmetic_does_not_overflow() {
+        let max = Number::Fraction(GenericFraction::new(u32::MAX,1u32));
+        let squared = max.clone() * max.clone();
+        assert!(matches!(squared,Number::BigFraction(_)));
+        assert_eq!(squared.to_string(),"18446744065119617025");
+
+        let result = squared / max.clone();
+        assert!(result.structural_eq(&max));
+
+        let tiny = Number::Fraction(GenericFraction::new(1u32,u32::MAX)) / Number::Fraction(GenericFraction::new(u32::MAX,1u32));
+        assert!(matches!(tiny,Number::BigFraction(_)));
+    }
+
+    #[test]
+    fn test_round_big_fraction() {
+        // 2^64 + 1/2 rounds to the even integer below it
+        let value = two_to_the_64() + Number::Fraction(GenericFraction::new(1u32,2u32));
+        assert!(value.round_to(0,RoundingMode::HalfEven).structural_eq(&two_to_the_64()));
+        assert_eq!(value.ceil().to_string(),"18446744073709551617");
+
+        // 2^64 is 18446744073709551616, which has twenty digits
+        let rounded = two_to_the_64().round_to_significant_figures(2,RoundingMode::HalfEven);
+        assert_eq!(rounded.to_string(),"18000000000000000000");
+    }
+
+    #[test]
+    fn test_big_fraction_compares_exactly() {
+        assert_eq!(two_to_the_64(),Number::Decimal(2_f64.powi(64)));
+        assert!(two_to_the_64() + Number::Fraction(GenericFraction::new(1u32,1u32)) > Number::Decimal(2_f64.powi(64)));
+        assert!(two_to_the_64() > Number::Fraction(GenericFraction::new(u32::MAX,1u32)));
+        assert!(-two_to_the_64() < Number::StandardForm(StandardForm::new(-1.0,19)));
+        assert!(Number::Decimal(f64::NAN) > two_to_the_64());
+    }
+
+    #[test]
+    fn test_big_fraction_into_f64() {
+        assert_eq!(f64::from(two_to_the_64()),2_f64.powi(64));
+
+        let third = Number::BigFraction(BigFraction::new(BigUint::one() << 200usize,BigUint::from(3u8) << 200usize));
+        assert_eq!(f64::from(third),1.0 / 3.0);
+
+        let huge = Number::BigFraction(BigFraction::new(BigUint::from(10u8).pow(400),BigUint::one()));
+        assert_eq!(f64::from(huge),f64::INFINITY);
+
+        assert_eq!((two_to_the_64() + 1_u32).to_f64_exact().unwrap_err().approximation(),2_f64.powi(64));
+    }
+
+    #[test]
+    fn test_big_fraction_into_standard_form() {
+        let sf = big_ratio_to_sf(&(BigUint::one() << 200usize),&BigUint::one());
+        assert_eq!((*sf.mantissa(),*sf.exponent()),(1.6069380442589903,60));
+
+        let sf = two_to_the_64().rem_with(&Number::Decimal(f64::INFINITY),PromotionPolicy::PreferStandardForm);
+        assert!(sf.structural_eq(&Number::StandardForm(StandardForm::new(1.8446744073709552,19))));
+
+        let sf = big_ratio_to_sf(&BigUint::one(),&(BigUint::from(3u8) << 200usize));
+        assert_eq!((*sf.mantissa(),*sf.exponent()),(2.074338425953714,-61));
+    }
+
+    #[test]
+    fn test_parse_big_fraction() {
+        let parsed = Number::try_from("18446744073709551616/1").unwrap();
+        assert!(parsed.structural_eq(&two_to_the_64()));
+
+        let parsed = Number::try_from("6/4").unwrap();
+        assert!(parsed.structural_eq(&Number::Fraction(GenericFraction::new(3u32,2u32))));
+    }
+
+    #[test]
+    #[cfg(feature="nom")]
+    fn test_nom_parses_big_fraction() {
+        let (rest,parsed) = crate::parse_number("-36893488147419103232/2").unwrap();
+        assert!(rest.is_empty());
+        assert!(parsed.structural_eq(&-two_to_the_64()));
+    }
+
+    #[test]
+    #[cfg(feature="hash")]
+    fn test_big_fraction_hashes_by_value() {
+        use std::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash_of = |number : &Number| {
+            let mut hasher = DefaultHasher::new();
+            number.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let third = Number::Fraction(GenericFraction::new(1u32,3u32));
+        assert_eq!(hash_of(&third),hash_of(&Number::BigFraction(widen(&GenericFraction::<u32>::new(1u32,3u32)))));
+        assert_eq!(hash_of(&two_to_the_64()),hash_of(&Number::Decimal(2_f64.powi(64))));
+    }
+}
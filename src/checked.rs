@@ -0,0 +1,202 @@
+use crate::{GenericNumber,FractionInteger,ArithmeticError,PromotionPolicy};
+use crate::number::to_integer;
+use crate::power::{checked_exact_power,checked_symbolic_power,sf_powi};
+use crate::promotion::checked_operate;
+use crate::special::{is_zero,Operation};
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Adds two numbers, failing instead of overflowing or producing NaN.
+    ///
+    /// The result is the same as that of `+`, with `ArithmeticError::Domain` for `∞ - ∞` and
+    /// `ArithmeticError::Overflow` for a finite sum that is too large to be represented.
+    pub fn checked_add(&self,rhs : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+        checked_operate(Operation::Add,self,rhs,PromotionPolicy::default())
+    }
+
+    /// Subtracts `rhs` from the number, failing instead of overflowing or producing NaN.
+    pub fn checked_sub(&self,rhs : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+        checked_operate(Operation::Sub,self,rhs,PromotionPolicy::default())
+    }
+
+    /// Multiplies two numbers, failing instead of overflowing, producing NaN or rounding a non-zero product to zero.
+    pub fn checked_mul(&self,rhs : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+        checked_operate(Operation::Mul,self,rhs,PromotionPolicy::default())
+    }
+
+    /// Divides the number by `rhs`, failing with `ArithmeticError::DivisionByZero` if `rhs` is zero.
+    pub fn checked_div(&self,rhs : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+        checked_operate(Operation::Div,self,rhs,PromotionPolicy::default())
+    }
+
+    /// Computes the remainder of dividing the number by `rhs`, failing with `ArithmeticError::DivisionByZero` if `rhs` is zero.
+    pub fn checked_rem(&self,rhs : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+        checked_operate(Operation::Rem,self,rhs,PromotionPolicy::default())
+    }
+
+    /// Raises the number to the power of `exponent`.
+    ///
+    /// Integer powers are raised like `powi`, so integers and fractions stay exact and numbers in standard form
+    /// stay in standard form, failing with `ArithmeticError::Overflow` or `ArithmeticError::PrecisionLoss` where `powi`
+    /// would fall back to a decimal. Other powers are computed as decimals.
+    /// Zero raised to a negative power fails with `ArithmeticError::DivisionByZero` and a negative number raised
+    /// to a power that is not an integer fails with `ArithmeticError::Domain`.
+    pub fn checked_pow(&self,exponent : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+        if self.is_nan() || exponent.is_nan() {
+            return Err(ArithmeticError::Domain);
+        }
+
+        if is_zero(self) && *exponent < 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        if let Some(power) = to_integer(exponent).and_then(|power| i32::try_from(power).ok()) {
+            if let Some(result) = checked_integer_power(self,power) {
+                return result;
+            }
+        }
+
+        let result = f64::from(self.clone()).powf(exponent.clone().into());
+        let finite = self.is_finite() && exponent.is_finite();
+
+        if result.is_nan() {
+            Err(ArithmeticError::Domain)
+        } else if finite && result.is_infinite() {
+            Err(ArithmeticError::Overflow)
+        } else if finite && result == 0.0 && !is_zero(self) {
+            Err(ArithmeticError::PrecisionLoss)
+        } else {
+            Ok(GenericNumber::Decimal(result))
+        }
+    }
+}
+
+/// Raises an integer, fraction or finite, non-zero number in standard form to an integer power without leaving its variant,
+/// returning `None` for the numbers that are raised as decimals.
+fn checked_integer_power<T : FractionInteger>(number : &GenericNumber<T>,power : i32) -> Option<Result<GenericNumber<T>,ArithmeticError>> {
+    match number {
+        GenericNumber::Decimal(_) => None,
+        GenericNumber::StandardForm(_) if is_zero(number) || !number.is_finite() => None,
+        GenericNumber::StandardForm(sf) => Some(match sf_powi(sf,power) {
+            GenericNumber::Decimal(d) if d.abs() < 1.0 => Err(ArithmeticError::PrecisionLoss),
+            GenericNumber::Decimal(_) => Err(ArithmeticError::Overflow),
+            result => Ok(result)
+        }),
+        GenericNumber::Symbolic(symbolic) => Some(checked_symbolic_power(symbolic,power)),
+        number => checked_exact_power(number,power.unsigned_abs()).map(|result| match power < 0 {
+            true => GenericNumber::Integer(1).checked_div(&result?),
+            false => result
+        })
+    }
+}
+
+#[cfg(not(feature="bigint"))]
+pub(crate) fn integer_pow<T : FractionInteger>(base : i128,power : u32) -> Result<GenericNumber<T>,ArithmeticError> {
+    base.checked_pow(power).map(GenericNumber::Integer).ok_or(ArithmeticError::Overflow)
+}
+
+#[cfg(feature="bigint")]
+pub(crate) fn integer_pow<T : FractionInteger>(base : i128,power : u32) -> Result<GenericNumber<T>,ArithmeticError> {
+    Ok(match base.checked_pow(power) {
+        Some(result) => GenericNumber::Integer(result),
+        None => crate::bigint::big_integer_pow(base,power)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fraction::GenericFraction;
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    #[test]
+    fn test_checked_results_match_operators() {
+        let operands = [
+            Number::Integer(-7),
+            Number::Decimal(2.5),
+            Number::StandardForm(StandardForm::new(1.2,3)),
+            Number::Fraction(GenericFraction::new(2u32,3u32)),
+            Number::Fraction(GenericFraction::new_neg(5u32,4u32)),
+        ];
+
+        for lhs in &operands {
+            for rhs in &operands {
+                assert!(lhs.checked_add(rhs).unwrap().structural_eq(&(lhs.clone() + rhs.clone())));
+                assert!(lhs.checked_sub(rhs).unwrap().structural_eq(&(lhs.clone() - rhs.clone())));
+                assert!(lhs.checked_mul(rhs).unwrap().structural_eq(&(lhs.clone() * rhs.clone())));
+                assert!(lhs.checked_div(rhs).unwrap().structural_eq(&(lhs.clone() / rhs.clone())));
+                assert!(lhs.checked_rem(rhs).unwrap().structural_eq(&(lhs.clone() % rhs.clone())),"{lhs:?} % {rhs:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        for zero in [Number::Integer(0),Number::Decimal(-0.0),Number::Fraction(GenericFraction::new(0u32,1u32))] {
+            assert_eq!(Number::Decimal(1.0).checked_div(&zero),Err(ArithmeticError::DivisionByZero));
+            assert_eq!(Number::Integer(1).checked_rem(&zero),Err(ArithmeticError::DivisionByZero));
+        }
+
+        assert_eq!(Number::Integer(0).checked_pow(&Number::Integer(-1)),Err(ArithmeticError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_domain_errors() {
+        let infinity = Number::Decimal(f64::INFINITY);
+
+        assert_eq!(infinity.checked_sub(&infinity),Err(ArithmeticError::Domain));
+        assert_eq!(infinity.checked_mul(&Number::Integer(0)),Err(ArithmeticError::Domain));
+        assert_eq!(Number::Decimal(f64::NAN).checked_add(&Number::Integer(1)),Err(ArithmeticError::Domain));
+        assert_eq!(Number::Integer(-8).checked_pow(&Number::Decimal(0.5)),Err(ArithmeticError::Domain));
+
+        assert_eq!(infinity.checked_add(&Number::Integer(1)),Ok(infinity));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(Number::Decimal(f64::MAX).checked_mul(&Number::Integer(2)),Err(ArithmeticError::Overflow));
+        assert_eq!(Number::Decimal(10.0).checked_pow(&Number::Integer(400)),Err(ArithmeticError::Overflow));
+        assert_eq!(Number::StandardForm(StandardForm::new(1.0,100)).checked_pow(&Number::Integer(2)),Err(ArithmeticError::Overflow));
+
+        let huge = Number::StandardForm(StandardForm::new(1.0,100));
+        assert_eq!(huge.checked_mul(&huge),Err(ArithmeticError::Overflow));
+
+        let max = Number::Fraction(GenericFraction::new(u32::MAX,1u32));
+        #[cfg(not(feature="bigint"))]
+        {
+            assert_eq!(max.checked_add(&max),Err(ArithmeticError::Overflow));
+            assert_eq!(Number::Integer(i128::MAX).checked_add(&Number::Integer(1)),Err(ArithmeticError::Overflow));
+            assert_eq!(Number::Integer(2).checked_pow(&Number::Integer(127)),Err(ArithmeticError::Overflow));
+            assert_eq!(Number::Fraction(GenericFraction::new(2u32,3u32)).checked_pow(&Number::Integer(40)),Err(ArithmeticError::Overflow));
+        }
+        #[cfg(feature="bigint")]
+        {
+            assert_eq!(max.checked_add(&max),Ok(Number::Integer(2 * u32::MAX as i128)));
+            assert_eq!(Number::Integer(i128::MAX).checked_add(&Number::Integer(1)),Ok(Number::Decimal(2_f64.powi(127))));
+            assert_eq!(Number::Integer(2).checked_pow(&Number::Integer(127)),Ok(Number::Decimal(2_f64.powi(127))));
+        }
+    }
+
+    #[test]
+    fn test_precision_loss() {
+        let tiny = Number::Decimal(f64::MIN_POSITIVE);
+        assert_eq!(tiny.checked_mul(&tiny),Err(ArithmeticError::PrecisionLoss));
+        assert_eq!(tiny.checked_div(&Number::Decimal(f64::MAX)),Err(ArithmeticError::PrecisionLoss));
+        assert_eq!(Number::StandardForm(StandardForm::new(1.0,-100)).checked_pow(&Number::Integer(4)),Err(ArithmeticError::PrecisionLoss));
+
+        // the quotient of these integers has no fraction over `u32`
+        #[cfg(not(feature="bigint"))]
+        assert_eq!(Number::Integer(1 << 40).checked_div(&Number::Integer(3)),Err(ArithmeticError::PrecisionLoss));
+    }
+
+    #[test]
+    fn test_exact_powers() {
+        assert!(Number::Integer(-3).checked_pow(&Number::Integer(3)).unwrap().structural_eq(&Number::Integer(-27)));
+        assert!(Number::Integer(2).checked_pow(&Number::Integer(-2)).unwrap().structural_eq(&Number::Fraction(GenericFraction::new(1u32,4u32))));
+        assert!(Number::Fraction(GenericFraction::new(2u32,3u32)).checked_pow(&Number::Integer(3)).unwrap().structural_eq(&Number::Fraction(GenericFraction::new(8u32,27u32))));
+        assert!(Number::StandardForm(StandardForm::new(2.0,10)).checked_pow(&Number::Integer(3)).unwrap().structural_eq(&Number::StandardForm(StandardForm::new(8.0,30))));
+        assert_eq!(Number::Decimal(4.0).checked_pow(&Number::Fraction(GenericFraction::new(1u32,2u32))),Ok(Number::Decimal(2.0)));
+    }
+}
@@ -0,0 +1,334 @@
+use std::cmp::Ordering;
+
+use fraction::{GenericFraction, Ratio, Sign, Zero};
+
+use crate::{GenericNumber,FractionInteger};
+use crate::number::{ratio_to_f64,fraction_to_f64,to_u128_fraction,float_to_integer};
+use crate::symbolic::evaluated;
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Returns `true` if both numbers are the same variant holding the same payload.
+    ///
+    /// Unlike `==`, which compares the numeric values of the numbers, this treats
+    /// `Number::Decimal(5.0)` and `Number::StandardForm(StandardForm::new(5.0,0))` as different.
+    /// Floating-point payloads are compared bit for bit, so a NaN is structurally equal to itself.
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (GenericNumber::Integer(i1), GenericNumber::Integer(i2)) => i1 == i2,
+            (GenericNumber::Decimal(d1), GenericNumber::Decimal(d2)) => d1.to_bits() == d2.to_bits(),
+            (GenericNumber::StandardForm(sf1), GenericNumber::StandardForm(sf2)) => {
+                sf1.mantissa().to_bits() == sf2.mantissa().to_bits() && sf1.exponent() == sf2.exponent()
+            },
+            (GenericNumber::Fraction(fr1), GenericNumber::Fraction(fr2)) => fr1 == fr2,
+            #[cfg(feature="bigint")]
+            (GenericNumber::BigFraction(fr1), GenericNumber::BigFraction(fr2)) => fr1 == fr2,
+            (GenericNumber::Symbolic(s1), GenericNumber::Symbolic(s2)) => {
+                s1.coefficient().structural_eq(s2.coefficient()) && s1.pi_power() == s2.pi_power() && s1.e_power() == s2.e_power()
+            },
+            _ => false
+        }
+    }
+}
+
+/// Two numbers are equal if they represent the same mathematical value, regardless of their notation.
+///
+/// Integers and fractions are compared exactly against each other and against the exact binary value of
+/// decimals and numbers in standard form. Unlike `f64`, NaN is considered equal to NaN
+/// so that `Eq` stays reflexive.
+impl<T : FractionInteger> PartialEq for GenericNumber<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T : FractionInteger> Eq for GenericNumber<T> {}
+
+impl<T : FractionInteger> PartialOrd for GenericNumber<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Numbers are totally ordered by their value, regardless of their notation.
+///
+/// The order follows `f64::total_cmp`, except that `-0.0` and `0.0` are equal and
+/// every NaN (`f64::NAN`, a NaN mantissa or `GenericFraction::NaN`) is equal to every other NaN:
+///
+/// `-∞ < ... < -1 < 0 < 1 < ... < +∞ < NaN`
+impl<T : FractionInteger> Ord for GenericNumber<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_nan(),other.is_nan()) {
+            (true,true) => Ordering::Equal,
+            (true,false) => Ordering::Greater,
+            (false,true) => Ordering::Less,
+            (false,false) => value_cmp(self,other).expect("only NaN is unordered")
+        }
+    }
+}
+
+/// Compares the numeric values of two numbers, returning `None` if either of them is NaN.
+pub(crate) fn value_cmp<T : FractionInteger>(lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Option<Ordering> {
+    match (lhs,rhs) {
+        // the constants are irrational, so numbers holding them are compared by their nearest float
+        (GenericNumber::Symbolic(_),_) | (_,GenericNumber::Symbolic(_)) => value_cmp(&evaluated(lhs),&evaluated(rhs)),
+        #[cfg(feature="bigint")]
+        (GenericNumber::BigFraction(_),_) | (_,GenericNumber::BigFraction(_)) => crate::bigint::big_value_cmp(lhs,rhs),
+        (GenericNumber::Integer(i1),GenericNumber::Integer(i2)) => Some(i1.cmp(i2)),
+        (GenericNumber::Integer(i),GenericNumber::Fraction(fr)) => integer_fraction_cmp(*i,fr),
+        (GenericNumber::Fraction(fr),GenericNumber::Integer(i)) => integer_fraction_cmp(*i,fr).map(Ordering::reverse),
+        (GenericNumber::Integer(i),other) => integer_float_cmp(*i,float_value(other)),
+        (other,GenericNumber::Integer(i)) => integer_float_cmp(*i,float_value(other)).map(Ordering::reverse),
+        (GenericNumber::Fraction(fr1),GenericNumber::Fraction(fr2)) => match (fr1,fr2) {
+            (GenericFraction::NaN,_) | (_,GenericFraction::NaN) => None,
+            _ => Some(fr1.cmp(fr2))
+        },
+        (GenericNumber::Fraction(fr),other) => fraction_float_cmp(fr,float_value(other)),
+        (other,GenericNumber::Fraction(fr)) => fraction_float_cmp(fr,float_value(other)).map(Ordering::reverse),
+        (lhs,rhs) => float_value(lhs).partial_cmp(&float_value(rhs))
+    }
+}
+
+fn float_value<T : FractionInteger>(number : &GenericNumber<T>) -> f64 {
+    match number {
+        GenericNumber::Decimal(d) => *d,
+        GenericNumber::StandardForm(sf) => sf.clone().into(),
+        GenericNumber::Integer(_) => unreachable!("integers are compared exactly"),
+        GenericNumber::Fraction(_) => unreachable!("fractions are compared exactly"),
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(_) => unreachable!("fractions are compared exactly"),
+        GenericNumber::Symbolic(_) => unreachable!("constants are evaluated before comparing"),
+    }
+}
+
+/// Compares an integer against a fraction.
+fn integer_fraction_cmp<T : FractionInteger>(integer : i128,fr : &GenericFraction<T>) -> Option<Ordering> {
+    let Some(fr) = to_u128_fraction(fr) else {
+        return (integer as f64).partial_cmp(&fraction_to_f64(fr));
+    };
+
+    let sign = match integer.is_negative() {
+        true => Sign::Minus,
+        false => Sign::Plus
+    };
+
+    match fr {
+        GenericFraction::NaN => None,
+        fr => Some(GenericFraction::Rational(sign,Ratio::from_integer(integer.unsigned_abs())).cmp(&fr))
+    }
+}
+
+/// Compares an integer against the exact value of a float.
+fn integer_float_cmp(integer : i128,float : f64) -> Option<Ordering> {
+    if float.is_nan() {
+        return None;
+    }
+
+    // floats beyond the range of `i128` are larger or smaller than every integer
+    match float_to_integer(float.trunc()) {
+        Some(whole) => 0.0_f64.partial_cmp(&float.fract()).map(|fract| integer.cmp(&whole).then(fract)),
+        None if float > 0.0 => Some(Ordering::Less),
+        None => Some(Ordering::Greater)
+    }
+}
+
+/// Compares a fraction against the exact value of a float.
+fn fraction_float_cmp<T : FractionInteger>(fr : &GenericFraction<T>,float : f64) -> Option<Ordering> {
+    let Some(fr) = to_u128_fraction(fr) else {
+        return fraction_to_f64(fr).partial_cmp(&float);
+    };
+
+    let (sign,ratio) = match &fr {
+        GenericFraction::NaN => return None,
+        GenericFraction::Infinity(sign) => return signed(*sign,f64::INFINITY).partial_cmp(&float),
+        GenericFraction::Rational(sign,ratio) => (sign,ratio)
+    };
+
+    if float.is_nan() {
+        return None;
+    }
+
+    let fraction_signum = match ratio.is_zero() {
+        true => 0,
+        false => signed(*sign,1.0) as i8
+    };
+
+    let float_signum = match float == 0.0 {
+        true => 0,
+        false => float.signum() as i8
+    };
+
+    if fraction_signum != float_signum || fraction_signum == 0 {
+        return Some(fraction_signum.cmp(&float_signum));
+    }
+
+    let ordering = ratio_float_cmp(ratio,float.abs());
+
+    Some(match sign {
+        Sign::Plus => ordering,
+        Sign::Minus => ordering.reverse()
+    })
+}
+
+/// Compares a non-negative ratio against a positive float.
+fn ratio_float_cmp(ratio : &Ratio<u128>,float : f64) -> Ordering {
+    if float.is_infinite() {
+        return Ordering::Less;
+    }
+
+    match float_to_ratio(float) {
+        Some(exact) => ratio.cmp(&exact),
+        // floats too large for `u128` are larger than any ratio that fits into it
+        None if float > 1.0 => Ordering::Less,
+        None => ratio_to_f64(*ratio.numer(),*ratio.denom()).total_cmp(&float)
+    }
+}
+
+/// Converts a positive, finite float into its exact value as a ratio, if it fits into `u128`.
+fn float_to_ratio(float : f64) -> Option<Ratio<u128>> {
+    let (mantissa,exponent) = float_parts(float);
+
+    match exponent >= 0 {
+        true if exponent as u32 <= mantissa.leading_zeros() => Some(Ratio::new_raw(mantissa << exponent,1)),
+        false if exponent > -128 => Some(Ratio::new_raw(mantissa,1u128 << -exponent)),
+        _ => None
+    }
+}
+
+/// Splits a positive, finite float into `mantissa * 2^exponent`, where the mantissa is odd unless the exponent is positive.
+pub(crate) fn float_parts(float : f64) -> (u128,i32) {
+    let bits = float.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction_bits = bits & 0x000f_ffff_ffff_ffff;
+
+    let (mut mantissa,mut exponent) = match biased_exponent {
+        0 => (fraction_bits as u128,-1074),
+        _ => ((fraction_bits | 1 << 52) as u128,biased_exponent - 1075)
+    };
+
+    let shift = (mantissa.trailing_zeros() as i32).min((-exponent).max(0));
+    mantissa >>= shift;
+    exponent += shift;
+
+    (mantissa,exponent)
+}
+
+fn signed(sign : Sign,value : f64) -> f64 {
+    match sign {
+        Sign::Plus => value,
+        Sign::Minus => -value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    #[test]
+    fn test_decimal_equals_standard_form() {
+        let decimal = Number::Decimal(5.0);
+        let sf = Number::StandardForm(StandardForm::new(500.0,-2));
+        assert_eq!(decimal,sf);
+        assert!(!decimal.structural_eq(&sf));
+    }
+
+    #[test]
+    fn test_fraction_equals_decimal_exactly() {
+        assert_eq!(Number::Fraction(GenericFraction::new(1u32,2u32)),Number::Decimal(0.5));
+        assert_eq!(Number::Fraction(GenericFraction::new_neg(3u32,4u32)),Number::Decimal(-0.75));
+        assert_ne!(Number::Fraction(GenericFraction::new(1u32,3u32)),Number::Decimal(1.0 / 3.0));
+        assert_ne!(Number::Fraction(GenericFraction::new(1u32,10u32)),Number::Decimal(0.1));
+    }
+
+    #[test]
+    fn test_fraction_equals_fraction() {
+        let a = Number::Fraction(GenericFraction::new(2u32,4u32));
+        let b = Number::Fraction(GenericFraction::new(3u32,6u32));
+        assert_eq!(a,b);
+        assert!(a.structural_eq(&b));
+    }
+
+    #[test]
+    fn test_fraction_equals_standard_form() {
+        let fraction = Number::Fraction(GenericFraction::new(1200u32,1u32));
+        let sf = Number::StandardForm(StandardForm::new(1.2,3));
+        assert_eq!(fraction,sf);
+    }
+
+    #[test]
+    fn test_zero_and_nan() {
+        assert_eq!(Number::Decimal(-0.0),Number::Fraction(GenericFraction::new(0u32,1u32)));
+        assert_eq!(Number::Decimal(f64::NAN),Number::Fraction(GenericFraction::nan()));
+        assert_ne!(Number::Decimal(f64::NAN),Number::Decimal(1.0));
+        assert_eq!(Number::Decimal(f64::INFINITY),Number::Fraction(GenericFraction::infinity()));
+    }
+
+    #[test]
+    fn test_cross_variant_partial_cmp() {
+        let third = Number::Fraction(GenericFraction::new(1u32,3u32));
+        assert!(third < Number::Decimal(0.34));
+        assert!(third > Number::Decimal(1.0 / 3.0));
+        assert!(Number::StandardForm(StandardForm::new(1.0,3)) > Number::Decimal(999.0));
+        assert!(Number::Decimal(1e300) > Number::Fraction(GenericFraction::new(u32::MAX,1u32)));
+        assert!(Number::Decimal(1e-300) < Number::Fraction(GenericFraction::new(1u32,u32::MAX)));
+    }
+
+    #[test]
+    fn test_sort_mixed_variants() {
+        let mut numbers = [
+            Number::Decimal(f64::NAN),
+            Number::StandardForm(StandardForm::new(2.0,1)),
+            Number::Fraction(GenericFraction::neg_infinity()),
+            Number::Decimal(0.5),
+            Number::Fraction(GenericFraction::nan()),
+            Number::Fraction(GenericFraction::new(1u32,3u32)),
+            Number::Decimal(f64::INFINITY),
+            Number::Decimal(-3.0),
+        ];
+        numbers.sort();
+
+        let expected = [
+            Number::Fraction(GenericFraction::neg_infinity()),
+            Number::Decimal(-3.0),
+            Number::Fraction(GenericFraction::new(1u32,3u32)),
+            Number::Decimal(0.5),
+            Number::StandardForm(StandardForm::new(2.0,1)),
+            Number::Decimal(f64::INFINITY),
+            Number::Decimal(f64::NAN),
+            Number::Fraction(GenericFraction::nan()),
+        ];
+
+        assert!(numbers.iter().zip(expected.iter()).all(|(a,b)| a.structural_eq(b)));
+    }
+
+    #[test]
+    fn test_nan_is_greatest() {
+        let nan = Number::Decimal(f64::NAN);
+        assert_eq!(nan.cmp(&Number::Decimal(f64::INFINITY)),Ordering::Greater);
+        assert_eq!(Number::Fraction(GenericFraction::nan()).cmp(&nan),Ordering::Equal);
+        assert!(nan > 1.0);
+    }
+
+    #[test]
+    fn test_btree_map_lookup_across_variants() {
+        let mut map = std::collections::BTreeMap::new();
+        let _ = map.insert(Number::Fraction(GenericFraction::new(1u32,2u32)),"half");
+        let _ = map.insert(Number::StandardForm(StandardForm::new(1.0,2)),"hundred");
+        let _ = map.insert(Number::Decimal(f64::NAN),"nan");
+
+        assert_eq!(map.get(&Number::Decimal(0.5)),Some(&"half"));
+        assert_eq!(map.get(&Number::Fraction(GenericFraction::new(100u32,1u32))),Some(&"hundred"));
+        assert_eq!(map.get(&Number::Fraction(GenericFraction::nan())),Some(&"nan"));
+        assert_eq!(map.len(),3);
+    }
+
+    #[test]
+    fn test_primitive_comparisons() {
+        assert_eq!(Number::Fraction(GenericFraction::new(10u32,2u32)),5);
+        assert_eq!(Number::StandardForm(StandardForm::new(2.5,0)),2.5);
+        assert!(Number::Fraction(GenericFraction::new(1u32,2u32)) < 1u8);
+    }
+}
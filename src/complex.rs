@@ -0,0 +1,404 @@
+use std::ops::{Add,Sub,Mul,Div,Neg};
+
+use crate::{GenericNumber,FractionInteger,ParsingComplexError};
+use crate::number::to_integer;
+use crate::special::is_zero;
+
+/// A complex number whose real and imaginary parts are each a `GenericNumber`, so they keep their own notation.
+///
+/// Addition, subtraction, multiplication, division and integer powers only use the arithmetic of the parts,
+/// so they are exact when the parts are integers or fractions. Functions that need trigonometry or logarithms,
+/// such as `exp`, `ln` and `argument`, give parts that are decimals.
+#[derive(Debug,Clone,PartialEq)]
+pub struct GenericComplexNumber<T : FractionInteger> {
+    /// The real part.
+    pub re : GenericNumber<T>,
+    /// The imaginary part.
+    pub im : GenericNumber<T>
+}
+
+/// A complex number whose fractions have a `u32` numerator and denominator.
+pub type ComplexNumber = GenericComplexNumber<u32>;
+
+impl<T : FractionInteger> GenericComplexNumber<T> {
+    /// Creates the complex number `re + im i`.
+    pub fn new(re : GenericNumber<T>,im : GenericNumber<T>) -> Self {
+        Self { re , im }
+    }
+
+    /// Creates the imaginary unit `i`.
+    pub fn i() -> Self {
+        Self::new(GenericNumber::Integer(0),GenericNumber::Integer(1))
+    }
+
+    /// Creates the complex number with modulus `modulus` and argument `argument` (in radians).
+    pub fn from_polar(modulus : GenericNumber<T>,argument : GenericNumber<T>) -> Self {
+        if is_zero(&argument) {
+            return Self::from(modulus);
+        }
+
+        let (modulus,argument) = (f64::from(modulus),f64::from(argument));
+        Self::from_floats(modulus * argument.cos(),modulus * argument.sin())
+    }
+
+    /// Returns the complex conjugate, whose imaginary part has the opposite sign.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.re.clone(),-self.im.clone())
+    }
+
+    /// Returns the distance of the number from zero, which is exact when either part is zero.
+    pub fn modulus(&self) -> GenericNumber<T> {
+        match (is_zero(&self.re),is_zero(&self.im)) {
+            (_,true) => abs(&self.re),
+            (true,false) => abs(&self.im),
+            (false,false) => GenericNumber::Decimal(f64::from(self.re.clone()).hypot(f64::from(self.im.clone())))
+        }
+    }
+
+    /// Returns the angle of the number from the positive real axis (in radians), between `-π` and `π`.
+    pub fn argument(&self) -> GenericNumber<T> {
+        match is_zero(&self.im) && self.re >= GenericNumber::Integer(0) {
+            true => GenericNumber::Integer(0),
+            false => GenericNumber::Decimal(f64::from(self.im.clone()).atan2(f64::from(self.re.clone())))
+        }
+    }
+
+    /// Returns the modulus and argument of the number, like `modulus` and `argument`.
+    pub fn to_polar(&self) -> (GenericNumber<T>,GenericNumber<T>) {
+        (self.modulus(),self.argument())
+    }
+
+    /// Computes `e` raised to the number.
+    pub fn exp(self) -> Self {
+        let (re,im) = self.to_floats();
+        let scale = re.exp();
+        match im == 0.0 {
+            true => Self::from_floats(scale,0.0),
+            false => Self::from_floats(scale * im.cos(),scale * im.sin())
+        }
+    }
+
+    /// Computes the principal natural logarithm, whose imaginary part is the argument of the number.
+    pub fn ln(self) -> Self {
+        let (re,im) = self.to_floats();
+        Self::from_floats(re.hypot(im).ln(),im.atan2(re))
+    }
+
+    /// Raises the number to the power of `exponent`, taking the principal value.
+    ///
+    /// Integer powers are computed by repeated multiplication, so they are exact when the parts of the number are.
+    /// Other powers are computed as `exp(exponent * ln(self))`.
+    pub fn pow(self,exponent : impl Into<Self>) -> Self {
+        let exponent = exponent.into();
+
+        if is_zero(&exponent.im) {
+            if let Some(power) = to_integer(&exponent.re) {
+                return self.powi(power);
+            }
+        }
+
+        if is_zero(&self.re) && is_zero(&self.im) && exponent.re > GenericNumber::Integer(0) {
+            return Self::from(GenericNumber::Integer(0));
+        }
+
+        (exponent * self.ln()).exp()
+    }
+
+    /// Raises the number to an integer power by squaring.
+    fn powi(self,power : i128) -> Self {
+        let mut result = Self::from(GenericNumber::Integer(1));
+        let (mut base,mut remaining) = (self,power.unsigned_abs());
+
+        while remaining > 0 {
+            if remaining % 2 == 1 {
+                result = result * base.clone();
+            }
+            remaining /= 2;
+            if remaining > 0 {
+                base = base.clone() * base;
+            }
+        }
+
+        match power < 0 {
+            true => Self::from(GenericNumber::Integer(1)) / result,
+            false => result
+        }
+    }
+
+    fn from_floats(re : f64,im : f64) -> Self {
+        Self::new(GenericNumber::Decimal(re),GenericNumber::Decimal(im))
+    }
+
+    fn to_floats(&self) -> (f64,f64) {
+        (f64::from(self.re.clone()),f64::from(self.im.clone()))
+    }
+}
+
+fn abs<T : FractionInteger>(number : &GenericNumber<T>) -> GenericNumber<T> {
+    match *number < GenericNumber::Integer(0) {
+        true => -number.clone(),
+        false => number.clone()
+    }
+}
+
+impl<T : FractionInteger> From<GenericNumber<T>> for GenericComplexNumber<T> {
+    fn from(value: GenericNumber<T>) -> Self {
+        Self::new(value,GenericNumber::Integer(0))
+    }
+}
+
+impl<T : FractionInteger> std::fmt::Display for GenericComplexNumber<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.im < GenericNumber::Integer(0) {
+            true => write!(f,"{} - {}i",self.re,-self.im.clone()),
+            false => write!(f,"{} + {}i",self.re,self.im)
+        }
+    }
+}
+
+impl<T : FractionInteger> TryFrom<&str> for GenericComplexNumber<T> {
+    type Error = ParsingComplexError;
+
+    /// Parses a real part and an imaginary part joined by `+` or `-`, such as `3/4 + 2i` or `1.2*10^3 - 4i`,
+    /// where either part can be any number and can be left out. The coefficient of `i` may be left out
+    /// or joined to it by `*`, so `i`, `-i` and `2*i` are also valid.
+    fn try_from(value : &str) -> Result<Self, Self::Error> {
+        let compact : String = value.chars().filter(|c| !c.is_whitespace()).collect();
+        if compact.is_empty() {
+            return Err(ParsingComplexError::Notation);
+        }
+
+        let Some(rest) = compact.strip_suffix('i') else {
+            return Ok(Self::from(GenericNumber::try_from(compact.as_str())?));
+        };
+
+        // signs after an exponent belong to the number rather than joining the parts
+        let split = rest.char_indices()
+            .rev()
+            .find(|&(index,c)| index > 0 && (c == '+' || c == '-') && !rest[..index].ends_with(['e','E','^']))
+            .map(|(index,_)| index);
+
+        let (re,im) = rest.split_at(split.unwrap_or(0));
+        let re = match re {
+            "" => GenericNumber::Integer(0),
+            re => GenericNumber::try_from(re)?
+        };
+        let im = match im.strip_suffix('*').unwrap_or(im) {
+            "" | "+" => GenericNumber::Integer(1),
+            "-" => GenericNumber::Integer(-1),
+            coefficient => GenericNumber::try_from(coefficient.strip_prefix('+').unwrap_or(coefficient))?
+        };
+
+        Ok(Self::new(re,im))
+    }
+}
+
+impl<T : FractionInteger> Neg for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re,-self.im)
+    }
+}
+
+impl<T : FractionInteger> Add for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re,self.im + other.im)
+    }
+}
+
+impl<T : FractionInteger> Sub for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re,self.im - other.im)
+    }
+}
+
+impl<T : FractionInteger> Mul for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let re = self.re.clone() * other.re.clone() - self.im.clone() * other.im.clone();
+        let im = self.re * other.im + self.im * other.re;
+        Self::new(re,im)
+    }
+}
+
+impl<T : FractionInteger> Div for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        // dividing by a real number avoids squaring it, which could overflow
+        if is_zero(&other.im) {
+            return self / other.re;
+        }
+
+        let denominator = other.re.clone() * other.re.clone() + other.im.clone() * other.im.clone();
+        let re = self.re.clone() * other.re.clone() + self.im.clone() * other.im.clone();
+        let im = self.im * other.re - self.re * other.im;
+        Self::new(re / denominator.clone(),im / denominator)
+    }
+}
+
+macro_rules! real_operand {
+    ($($trait : ident => $fn : ident),*) => {
+        $(
+            impl<T : FractionInteger> $trait<GenericComplexNumber<T>> for GenericNumber<T> {
+                type Output = GenericComplexNumber<T>;
+
+                fn $fn(self, other: GenericComplexNumber<T>) -> GenericComplexNumber<T> {
+                    GenericComplexNumber::from(self).$fn(other)
+                }
+            }
+        )*
+    };
+}
+
+real_operand!(
+    Add => add,
+    Sub => sub,
+    Mul => mul,
+    Div => div
+);
+
+impl<T : FractionInteger> Add<GenericNumber<T>> for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn add(self, other: GenericNumber<T>) -> Self {
+        Self::new(self.re + other,self.im)
+    }
+}
+
+impl<T : FractionInteger> Sub<GenericNumber<T>> for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn sub(self, other: GenericNumber<T>) -> Self {
+        Self::new(self.re - other,self.im)
+    }
+}
+
+impl<T : FractionInteger> Mul<GenericNumber<T>> for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn mul(self, other: GenericNumber<T>) -> Self {
+        Self::new(self.re * other.clone(),self.im * other)
+    }
+}
+
+impl<T : FractionInteger> Div<GenericNumber<T>> for GenericComplexNumber<T> {
+    type Output = Self;
+
+    fn div(self, other: GenericNumber<T>) -> Self {
+        Self::new(self.re / other.clone(),self.im / other)
+    }
+}
+
+#[cfg(feature="complex")]
+impl<T : FractionInteger> From<GenericComplexNumber<T>> for num_complex::Complex<f64> {
+    fn from(value: GenericComplexNumber<T>) -> Self {
+        num_complex::Complex::new(f64::from(value.re),f64::from(value.im))
+    }
+}
+
+#[cfg(feature="complex")]
+impl<T : FractionInteger> From<num_complex::Complex<f64>> for GenericComplexNumber<T> {
+    fn from(value: num_complex::Complex<f64>) -> Self {
+        Self::from_floats(value.re,value.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fraction::GenericFraction;
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn complex(re : i128,im : i128) -> ComplexNumber {
+        ComplexNumber::new(Number::Integer(re),Number::Integer(im))
+    }
+
+    fn assert_close(actual : &ComplexNumber,re : f64,im : f64) {
+        let (actual_re,actual_im) = actual.to_floats();
+        assert!((actual_re - re).abs() < 1e-12 && (actual_im - im).abs() < 1e-12,"{actual} is not close to {re} + {im}i");
+    }
+
+    #[test]
+    fn test_exact_arithmetic() {
+        assert!((ComplexNumber::i() * ComplexNumber::i()).re.structural_eq(&Number::Integer(-1)));
+        assert_eq!(complex(1,2) * complex(3,-1),complex(5,5));
+
+        let quotient = complex(1,0) / complex(1,1);
+        let half = Number::Fraction(GenericFraction::new(1u32,2u32));
+        assert!(quotient.re.structural_eq(&half) && quotient.im.structural_eq(&-half));
+
+        assert_eq!(complex(1,2) + Number::Integer(3),complex(4,2));
+        assert_eq!(Number::Integer(2) * complex(1,-2),complex(2,-4));
+        assert_eq!(complex(3,4).conjugate(),complex(3,-4));
+    }
+
+    #[test]
+    fn test_polar() {
+        assert_eq!(complex(3,4).modulus(),5);
+        assert!(complex(-2,0).modulus().structural_eq(&Number::Integer(2)));
+        assert!(complex(5,0).argument().structural_eq(&Number::Integer(0)));
+        assert_eq!(complex(0,1).argument(),PI / 2.0);
+        assert_eq!(complex(-1,0).argument(),PI);
+
+        let (modulus,argument) = complex(1,1).to_polar();
+        assert_close(&ComplexNumber::from_polar(modulus,argument),1.0,1.0);
+    }
+
+    #[test]
+    fn test_exp_ln_pow() {
+        assert_close(&ComplexNumber::new(Number::Integer(0),Number::Decimal(PI)).exp(),-1.0,0.0);
+        assert_close(&complex(-1,0).ln(),0.0,PI);
+
+        let squared = complex(1,1).pow(Number::Integer(2));
+        assert!(squared.re.structural_eq(&Number::Integer(0)) && squared.im.structural_eq(&Number::Integer(2)));
+        assert_eq!(complex(0,2).pow(Number::Integer(-1)),ComplexNumber::new(Number::Integer(0),Number::Fraction(GenericFraction::new_neg(1u32,2u32))));
+
+        // the principal square root of -1
+        assert_close(&complex(-1,0).pow(Number::Decimal(0.5)),0.0,1.0);
+        assert_close(&ComplexNumber::i().pow(ComplexNumber::i()),(-PI / 2.0).exp(),0.0);
+        assert_eq!(complex(0,0).pow(Number::Decimal(0.5)),complex(0,0));
+    }
+
+    #[test]
+    fn test_parsing() {
+        let parsed = ComplexNumber::try_from("3/4 + 2i").unwrap();
+        assert!(parsed.re.structural_eq(&Number::Fraction(GenericFraction::new(3u32,4u32))) && parsed.im.structural_eq(&Number::Integer(2)));
+
+        let parsed = ComplexNumber::try_from("1.2*10^3 - 4i").unwrap();
+        assert!(parsed.re.structural_eq(&Number::StandardForm(StandardForm::new(1.2,3))) && parsed.im.structural_eq(&Number::Integer(-4)));
+
+        assert_eq!(ComplexNumber::try_from("-i").unwrap(),complex(0,-1));
+        assert_eq!(ComplexNumber::try_from("2.5e-3i").unwrap(),ComplexNumber::new(Number::Integer(0),Number::Decimal(2.5e-3)));
+        assert_eq!(ComplexNumber::try_from("1e-3+2*i").unwrap(),ComplexNumber::new(Number::Decimal(1e-3),Number::Integer(2)));
+        assert_eq!(ComplexNumber::try_from("-7").unwrap(),complex(-7,0));
+
+        assert!(matches!(ComplexNumber::try_from(" "),Err(ParsingComplexError::Notation)));
+        assert!(matches!(ComplexNumber::try_from("1 + xi"),Err(ParsingComplexError::Number(_))));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(complex(1,-2).to_string(),"1 - 2i");
+        assert_eq!(ComplexNumber::try_from("3/4 + 2i").unwrap().to_string(),"3/4 + 2i");
+    }
+
+    #[cfg(feature="complex")]
+    #[test]
+    fn test_num_complex() {
+        let converted = num_complex::Complex::<f64>::from(ComplexNumber::try_from("3/4 - 2i").unwrap());
+        assert_eq!(converted,num_complex::Complex::new(0.75,-2.0));
+        assert_eq!(ComplexNumber::from(converted),ComplexNumber::new(Number::Decimal(0.75),Number::Decimal(-2.0)));
+    }
+}
@@ -35,4 +35,83 @@ impl ParsingNumberError {
     pub fn standardform(&self) -> &ParsingStandardFormError {
         &self.sf
     }
-}
\ No newline at end of file
+}
+
+/// Error returned when a number cannot be converted into an `f64` without losing precision.
+#[derive(Error,Debug,Clone,Copy,PartialEq)]
+#[error("The number cannot be represented exactly as an f64, the nearest f64 is {approximation}")]
+pub struct LossyConversion {
+    approximation : f64
+}
+
+impl LossyConversion {
+    pub(crate) fn new(approximation : f64) -> Self {
+        Self { approximation }
+    }
+
+    /// Retrieves the nearest `f64` to the number that failed to convert.
+    pub fn approximation(&self) -> f64 {
+        self.approximation
+    }
+}
+
+/// Error returned by checked arithmetic when an operation has no result that a `Number` can hold.
+#[derive(Error,Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum ArithmeticError {
+    /// The result is too large to be represented, or an integer or fraction result does not fit its type.
+    #[error("The result of the operation overflowed")]
+    Overflow,
+
+    /// The divisor is zero.
+    #[error("Attempted to divide by zero")]
+    DivisionByZero,
+
+    /// The operation is undefined for its operands, such as `∞ - ∞` or any operation involving NaN.
+    #[error("The operation is undefined for its operands")]
+    Domain,
+
+    /// The result would have to be rounded, either because a non-zero result is too small to be represented
+    /// or because an exact result does not fit into an integer or fraction.
+    #[error("The result of the operation cannot be represented without losing precision")]
+    PrecisionLoss,
+}
+
+/// Error type for parsing a number with an uncertainty, written as `1.23(4)` or `1.23 ± 0.04`.
+#[derive(Error,Debug,Clone)]
+pub enum ParsingUncertainError {
+    /// The string has neither an uncertainty in parentheses nor one after `±`.
+    #[error("Expected an uncertainty in parentheses or after ±")]
+    Notation,
+
+    /// The central value or the uncertainty is not a valid number.
+    #[error(transparent)]
+    Number(#[from] ParsingNumberError),
+
+    /// The uncertainty is negative or NaN.
+    #[error("The uncertainty must not be negative or NaN")]
+    InvalidUncertainty,
+}
+
+/// Error type for parsing a complex number, written as `3/4 + 2i` or `1.2*10^3 - 4i`.
+#[derive(Error,Debug,Clone)]
+pub enum ParsingComplexError {
+    /// The string is empty or its imaginary part is not followed by `i`.
+    #[error("Expected a real part, an imaginary part ending in i, or both joined by + or -")]
+    Notation,
+
+    /// The real part or the coefficient of the imaginary part is not a valid number.
+    #[error(transparent)]
+    Number(#[from] ParsingNumberError),
+}
+
+/// Error type for parsing a multiple of `π` and `e`, written as `2π/3`, `2pi/3` or `3e^2`.
+#[derive(Error,Debug,Clone)]
+pub enum ParsingSymbolicError {
+    /// The string, its numerator or its denominator is empty, the denominator is zero or a power does not fit into an `i32`.
+    #[error("Expected a coefficient, a constant such as π or e, or both, optionally over a denominator")]
+    Notation,
+
+    /// The coefficient or the denominator is not a valid number.
+    #[error(transparent)]
+    Number(#[from] ParsingNumberError),
+}
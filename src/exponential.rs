@@ -0,0 +1,205 @@
+use std::f64::consts::{LN_10,LOG2_10};
+
+use fraction::GenericFraction;
+
+use crate::{GenericNumber,FractionInteger};
+use crate::number::{is_exact,to_integer};
+use crate::special::is_zero;
+
+/// The largest denominator tried when looking for an exact rational logarithm, such as `log_4(8) = 3/2`.
+const MAX_LOG_DENOMINATOR : u32 = 12;
+
+/// The largest power of the base that is computed when checking for an exact logarithm, which keeps the check fast.
+const MAX_LOG_POWER : f64 = 4096.0;
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Computes `e` raised to the number, which is exactly one for an exact zero.
+    pub fn exp(&self) -> GenericNumber<T> {
+        match is_exact(self) && is_zero(self) {
+            true => GenericNumber::Integer(1),
+            false => GenericNumber::Decimal(f64::from(self.clone()).exp())
+        }
+    }
+
+    /// Computes two raised to the number, which is exact when the number is an integer or a fraction with an integer value.
+    pub fn exp2(&self) -> GenericNumber<T> {
+        let power = to_integer(self).filter(|_| is_exact(self)).and_then(|power| i32::try_from(power).ok());
+        match power {
+            Some(power) => GenericNumber::Integer(2).powi(power),
+            None => GenericNumber::Decimal(f64::from(self.clone()).exp2())
+        }
+    }
+
+    /// Computes `e^x - 1` precisely even when `x` is close to zero.
+    pub fn exp_m1(&self) -> GenericNumber<T> {
+        match is_exact(self) && is_zero(self) {
+            true => GenericNumber::Integer(0),
+            false => GenericNumber::Decimal(f64::from(self.clone()).exp_m1())
+        }
+    }
+
+    /// Computes the natural logarithm, which is exactly zero for an exact one.
+    ///
+    /// Numbers in standard form and big fractions are split into a mantissa and a power of ten,
+    /// so their logarithms are finite even when they are beyond the range of `f64`. The same holds for the other logarithms.
+    pub fn ln(&self) -> GenericNumber<T> {
+        match is_exact(self) && *self == 1 {
+            true => GenericNumber::Integer(0),
+            false => GenericNumber::Decimal(float_log(self,f64::ln,LN_10))
+        }
+    }
+
+    /// Computes `ln(1 + x)` precisely even when `x` is close to zero.
+    pub fn ln_1p(&self) -> GenericNumber<T> {
+        match decimal_parts(self) {
+            _ if is_exact(self) && is_zero(self) => GenericNumber::Integer(0),
+            // adding one makes no difference at this magnitude
+            Some((_,exponent)) if exponent > f64::DIGITS as i64 => self.ln(),
+            _ => GenericNumber::Decimal(f64::from(self.clone()).ln_1p())
+        }
+    }
+
+    /// Computes the base 10 logarithm.
+    ///
+    /// Integers and fractions whose logarithm is rational give an exact result, so `log10(1000)` is `3`
+    /// and `log10(1/100)` is `-2`, as do numbers in standard form with a mantissa of one.
+    pub fn log10(&self) -> GenericNumber<T> {
+        if let GenericNumber::StandardForm(sf) = self {
+            if *sf.mantissa() == 1.0 {
+                return GenericNumber::Integer(*sf.exponent() as i128);
+            }
+        }
+
+        exact_log(self,&GenericNumber::Integer(10)).unwrap_or_else(|| GenericNumber::Decimal(float_log(self,f64::log10,1.0)))
+    }
+
+    /// Computes the base 2 logarithm, which is exact for powers of two like `log10`.
+    pub fn log2(&self) -> GenericNumber<T> {
+        exact_log(self,&GenericNumber::Integer(2)).unwrap_or_else(|| GenericNumber::Decimal(float_log(self,f64::log2,LOG2_10)))
+    }
+
+    /// Computes the logarithm in `base`, which is exact when the number and `base` are integers or fractions
+    /// and one is a rational power of the other, so `log(8,4)` is `3/2`.
+    pub fn log(&self,base : &GenericNumber<T>) -> GenericNumber<T> {
+        exact_log(self,base).unwrap_or_else(|| GenericNumber::Decimal(float_log(self,f64::ln,LN_10) / float_log(base,f64::ln,LN_10)))
+    }
+}
+
+/// Splits a number in standard form or a big fraction into its mantissa and power of ten.
+fn decimal_parts<T : FractionInteger>(number : &GenericNumber<T>) -> Option<(f64,i64)> {
+    match number {
+        GenericNumber::StandardForm(sf) if sf.mantissa().is_finite() && *sf.mantissa() != 0.0 => Some((*sf.mantissa(),*sf.exponent() as i64)),
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(fr) => crate::bigint::big_fraction_decimal_parts(fr),
+        _ => None
+    }
+}
+
+/// Takes a logarithm of the number, adding `exponent * log_of_ten` for numbers that are split into a mantissa and power of ten.
+fn float_log<T : FractionInteger>(number : &GenericNumber<T>,log : fn(f64) -> f64,log_of_ten : f64) -> f64 {
+    match decimal_parts(number) {
+        Some((mantissa,exponent)) => log(mantissa) + exponent as f64 * log_of_ten,
+        None => log(f64::from(number.clone()))
+    }
+}
+
+/// Finds the logarithm of an integer or fraction in an integer or fraction `base` if it is a fraction `p/q`,
+/// by checking that `number^q = base^p` exactly for the nearest `p` to each small `q`.
+fn exact_log<T : FractionInteger>(number : &GenericNumber<T>,base : &GenericNumber<T>) -> Option<GenericNumber<T>> {
+    let positive = |number : &GenericNumber<T>| is_exact(number) && *number > 0;
+    if !positive(number) || !positive(base) || *base == 1 {
+        return None;
+    }
+
+    let estimate = float_log(number,f64::ln,LN_10) / float_log(base,f64::ln,LN_10);
+    if !estimate.is_finite() {
+        return None;
+    }
+
+    (1..=MAX_LOG_DENOMINATOR).find_map(|denom| {
+        let numer = (estimate * denom as f64).round();
+        if numer.abs() > MAX_LOG_POWER || (estimate * denom as f64 - numer).abs() > 1e-9 * numer.abs().max(1.0) {
+            return None;
+        }
+
+        let numer = numer as i32;
+        let (lhs,rhs) = (number.powi(denom as i32),base.powi(numer));
+        if !is_exact(&lhs) || !is_exact(&rhs) || lhs != rhs {
+            return None;
+        }
+
+        match denom {
+            1 => Some(GenericNumber::Integer(numer as i128)),
+            denom => {
+                let (numer_part,denom_part) = (T::from_u32(numer.unsigned_abs())?,T::from_u32(denom)?);
+                Some(GenericNumber::Fraction(match numer < 0 {
+                    true => GenericFraction::new_neg(numer_part,denom_part),
+                    false => GenericFraction::new(numer_part,denom_part)
+                }))
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_exact_logarithms() {
+        assert!(Number::Integer(1000).log10().structural_eq(&Number::Integer(3)));
+        assert!(fraction(1,100).log10().structural_eq(&Number::Integer(-2)));
+        assert!(fraction(1,8).log2().structural_eq(&Number::Integer(-3)));
+        assert!(Number::Integer(8).log(&Number::Integer(4)).structural_eq(&fraction(3,2)));
+        assert!(fraction(4,9).log(&fraction(2,3)).structural_eq(&Number::Integer(2)));
+        assert!(Number::Integer(1).ln().structural_eq(&Number::Integer(0)));
+        assert!(Number::StandardForm(StandardForm::new(1.0,-100)).log10().structural_eq(&Number::Integer(-100)));
+    }
+
+    #[test]
+    fn test_inexact_logarithms() {
+        assert!(Number::Integer(2).log10().structural_eq(&Number::Decimal(2_f64.log10())));
+        assert!(Number::Integer(10).ln().structural_eq(&Number::Decimal(LN_10)));
+        assert!(Number::Integer(-1).ln().is_nan());
+        assert!(Number::Integer(0).log2().structural_eq(&Number::Decimal(f64::NEG_INFINITY)));
+
+        // the exponent is added to the logarithm of the mantissa, rather than converting the number into a float
+        let tiny = Number::StandardForm(StandardForm::new(2.0,-120));
+        assert!(tiny.log10().structural_eq(&Number::Decimal(2_f64.log10() - 120.0)));
+        assert!(tiny.ln().structural_eq(&Number::Decimal(2_f64.ln() - 120.0 * LN_10)));
+    }
+
+    #[test]
+    fn test_exponentials() {
+        assert!(Number::Integer(0).exp().structural_eq(&Number::Integer(1)));
+        assert!(Number::Integer(1).exp().structural_eq(&Number::Decimal(std::f64::consts::E)));
+        assert!(Number::Integer(-3).exp2().structural_eq(&fraction(1,8)));
+        assert!(Number::Integer(10).exp2().structural_eq(&Number::Integer(1024)));
+        assert!(fraction(1,2).exp2().structural_eq(&Number::Decimal(std::f64::consts::SQRT_2)));
+
+        assert!(Number::Decimal(1e-20).exp_m1().structural_eq(&Number::Decimal(1e-20)));
+        assert!(Number::Decimal(1e-20).ln_1p().structural_eq(&Number::Decimal(1e-20)));
+        assert!(Number::Integer(0).ln_1p().structural_eq(&Number::Integer(0)));
+    }
+
+    #[cfg(feature="bigint")]
+    #[test]
+    fn test_logarithms_beyond_floats() {
+        let tiny = Number::Integer(10).powi(-400);
+        assert!(matches!(tiny,Number::BigFraction(_)));
+        assert!(tiny.log10().structural_eq(&Number::Integer(-400)));
+        assert!(tiny.ln().structural_eq(&Number::Decimal(-400.0 * LN_10)));
+
+        let huge = Number::Integer(3).powi(1000);
+        assert!(huge.log(&Number::Integer(9)).structural_eq(&Number::Integer(500)));
+        assert!((f64::from(huge.ln_1p()) - 1000.0 * 3_f64.ln()).abs() < 1e-9);
+    }
+}
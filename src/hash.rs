@@ -1,15 +1,146 @@
 use std::hash::{Hash, Hasher};
 use ordered_float::OrderedFloat;
 
-use crate::Number;
+pub use ordered_float;
 
-impl Hash for Number { 
+use crate::{GenericNumber,FractionInteger};
+
+/// Numbers are hashed by their value so that numbers which compare equal hash the same,
+/// regardless of their notation.
+///
+/// Values that are exactly representable as an `f64` are hashed as that `f64`, with `-0.0` and `0.0`
+/// as well as all NaNs hashing the same. The remaining integers and fractions are hashed as reduced fractions,
+/// and multiples of `π` and `e` as their nearest `f64`, which they are compared by.
+impl<T : FractionInteger + Hash> Hash for GenericNumber<T> { 
     fn hash<H>(&self, state: &mut H) where H: Hasher {
-        use Number::*;
         match self {
-            Decimal(float) => OrderedFloat(*float).hash(state),
-            StandardForm(sf) => (*sf).hash(state),
-            Fraction(fr) => fr.hash(state)
+            GenericNumber::Integer(i) => match self.to_f64_exact() {
+                Ok(float) => OrderedFloat(float).hash(state),
+                #[cfg(feature="bigint")]
+                Err(_) => crate::bigint::integer_to_big_fraction(*i).hash(state),
+                // only a fraction that fits the integer can equal it
+                #[cfg(not(feature="bigint"))]
+                Err(_) => match crate::number::to_fraction(self) {
+                    Some(fr) => fr.hash(state),
+                    None => i.hash(state)
+                }
+            },
+            GenericNumber::Fraction(fr) => match self.to_f64_exact() {
+                Ok(float) => OrderedFloat(float).hash(state),
+                // a fraction can equal a big fraction, so both are hashed as big fractions
+                #[cfg(feature="bigint")]
+                Err(_) => crate::bigint::widen(fr).hash(state),
+                #[cfg(not(feature="bigint"))]
+                Err(_) => fr.hash(state)
+            },
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => match self.to_f64_exact() {
+                Ok(float) => OrderedFloat(float).hash(state),
+                Err(_) => fr.hash(state)
+            },
+            other => OrderedFloat(f64::from(other.clone())).hash(state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, hash_map::DefaultHasher};
+
+    use fraction::GenericFraction;
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn hash_of(number : &Number) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        number.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn assert_consistent(numbers : &[Number]) {
+        for a in numbers {
+            for b in numbers {
+                if a == b {
+                    assert_eq!(hash_of(a),hash_of(b),"{a:?} == {b:?} but their hashes differ");
+                }
+            }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_equal_values_hash_equal_across_variants() {
+        assert_consistent(&[
+            Number::Decimal(0.5),
+            Number::StandardForm(StandardForm::new(5.0,-1)),
+            Number::Fraction(GenericFraction::new(1u32,2u32)),
+            Number::Fraction(GenericFraction::new(2u32,4u32)),
+            Number::Decimal(1200.0),
+            Number::StandardForm(StandardForm::new(1.2,3)),
+            Number::Fraction(GenericFraction::new(1200u32,1u32)),
+            Number::Fraction(GenericFraction::new(1u32,3u32)),
+            Number::Fraction(GenericFraction::new(2u32,6u32)),
+            Number::Decimal(1.0 / 3.0),
+        ]);
+    }
+
+    #[test]
+    fn test_zero_and_nan_hash_equal() {
+        assert_consistent(&[
+            Number::Decimal(0.0),
+            Number::Decimal(-0.0),
+            Number::StandardForm(StandardForm::new(0.0,0)),
+            Number::Fraction(GenericFraction::new(0u32,1u32)),
+            Number::Fraction(GenericFraction::neg_zero()),
+            Number::Decimal(f64::NAN),
+            Number::Fraction(GenericFraction::nan()),
+            Number::Decimal(f64::INFINITY),
+            Number::Fraction(GenericFraction::infinity()),
+            Number::Decimal(f64::NEG_INFINITY),
+            Number::Fraction(GenericFraction::neg_infinity()),
+        ]);
+
+        assert_eq!(hash_of(&Number::Decimal(-0.0)),hash_of(&Number::Fraction(GenericFraction::new(0u32,1u32))));
+        assert_eq!(hash_of(&Number::Decimal(f64::NAN)),hash_of(&Number::Fraction(GenericFraction::nan())));
+    }
+
+    #[test]
+    fn test_hash_map_lookup_across_variants() {
+        let mut map = HashMap::new();
+        let _ = map.insert(Number::Fraction(GenericFraction::new(3u32,4u32)),"three quarters");
+        let _ = map.insert(Number::Decimal(100.0),"hundred");
+
+        assert_eq!(map.get(&Number::Decimal(0.75)),Some(&"three quarters"));
+        assert_eq!(map.get(&Number::StandardForm(StandardForm::new(1.0,2))),Some(&"hundred"));
+        assert_eq!(map.get(&Number::Fraction(GenericFraction::new(1u32,3u32))),None);
+    }
+
+    #[test]
+    fn test_hash_generic_fraction() {
+        let mut hasher = DefaultHasher::new();
+        GenericNumber::<u64>::Fraction(GenericFraction::new(1u64,2u64)).hash(&mut hasher);
+        assert_eq!(hasher.finish(),hash_of(&Number::Decimal(0.5)));
+    }
+
+    #[test]
+    fn test_integers_hash_equal_to_other_variants() {
+        assert_consistent(&[
+            Number::Integer(0),
+            Number::Decimal(-0.0),
+            Number::Integer(1200),
+            Number::Decimal(1200.0),
+            Number::Fraction(GenericFraction::new(1200u32,1u32)),
+            Number::Integer(u32::MAX.into()),
+            Number::Fraction(GenericFraction::new(u32::MAX,1u32)),
+        ]);
+
+        let big = (1u64 << 60) + 1;
+        let mut hasher = DefaultHasher::new();
+        GenericNumber::<u64>::Fraction(GenericFraction::new(big,1u64)).hash(&mut hasher);
+        let mut other = DefaultHasher::new();
+        GenericNumber::<u64>::Integer(big.into()).hash(&mut other);
+        assert_eq!(hasher.finish(),other.finish());
+    }
+}
@@ -0,0 +1,462 @@
+use std::ops::{Add,Sub,Mul,Div,Neg};
+use std::f64::consts::{PI,TAU};
+
+use crate::{GenericNumber,FractionInteger,PromotionPolicy};
+use crate::number::is_exact;
+use crate::promotion::checked_operate;
+use crate::special::Operation;
+
+#[cfg(feature="num")]
+use num_traits::Pow;
+
+/// A closed interval of numbers, whose arithmetic gives bounds that are guaranteed to contain every possible result.
+///
+/// Arithmetic between endpoints that are integers or fractions is exact. Otherwise the endpoints are bounded by
+/// decimals, whose arithmetic rounds the lower endpoint down and the upper endpoint up. The standard library does not
+/// round functions such as `sin` correctly, so their endpoints are widened by a further unit in the last place.
+///
+/// Division by an interval containing zero gives the whole real line, and functions whose endpoints fall
+/// outside their domain, such as `asin` of `[-2, 0]`, have NaN endpoints.
+#[derive(Debug,Clone)]
+pub struct GenericInterval<T : FractionInteger> {
+    lower : GenericNumber<T>,
+    upper : GenericNumber<T>
+}
+
+/// An interval whose fractions have a `u32` numerator and denominator.
+pub type Interval = GenericInterval<u32>;
+
+/// How a function of one variable varies, which decides where the extremes over an interval lie.
+#[derive(Debug,Clone,Copy)]
+pub(crate) enum Shape {
+    Increasing,
+    Decreasing,
+    /// Decreases up to zero and increases after it.
+    Even,
+    /// Oscillates between -1 and 1 with a period of `2π`, peaking at `peak`.
+    Periodic { peak : f64 },
+    /// Increases between poles at `π/2 + kπ`.
+    Tangent
+}
+
+impl<T : FractionInteger> GenericInterval<T> {
+    /// Creates the interval from `lower` to `upper`, including both.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lower` is greater than `upper`.
+    pub fn new(lower : GenericNumber<T>,upper : GenericNumber<T>) -> Self {
+        assert!(lower <= upper,"the lower endpoint of an interval must not be above its upper endpoint");
+        Self { lower , upper }
+    }
+
+    /// Retrieves the lower endpoint.
+    pub fn lower(&self) -> &GenericNumber<T> {
+        &self.lower
+    }
+
+    /// Retrieves the upper endpoint.
+    pub fn upper(&self) -> &GenericNumber<T> {
+        &self.upper
+    }
+
+    /// Returns `true` if the number lies in the interval, comparing exact values.
+    pub fn contains(&self,number : &GenericNumber<T>) -> bool {
+        self.lower <= *number && *number <= self.upper
+    }
+
+    /// Returns the width of the interval, rounded up if it cannot be computed exactly.
+    pub fn width(&self) -> GenericNumber<T> {
+        match exact(Operation::Sub,&self.upper,&self.lower) {
+            Some(width) => width,
+            None => GenericNumber::Decimal((float_above(&self.upper) - float_below(&self.lower)).next_up())
+        }
+    }
+
+    /// Returns the number halfway between the endpoints, which is the nearest decimal if it cannot be computed exactly.
+    pub fn midpoint(&self) -> GenericNumber<T> {
+        let exact_midpoint = exact(Operation::Add,&self.lower,&self.upper)
+            .and_then(|sum| exact(Operation::Div,&sum,&GenericNumber::Integer(2)));
+
+        match exact_midpoint {
+            Some(midpoint) => midpoint,
+            // halving before adding cannot overflow
+            None => GenericNumber::Decimal(f64::from(self.lower.clone()) / 2.0 + f64::from(self.upper.clone()) / 2.0)
+        }
+    }
+
+    /// Returns the numbers lying in both intervals, or `None` if they are disjoint.
+    pub fn intersect(&self,other : &Self) -> Option<Self> {
+        let lower = (&self.lower).max(&other.lower).clone();
+        let upper = (&self.upper).min(&other.upper).clone();
+        (lower <= upper).then_some(Self { lower , upper })
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn hull(&self,other : &Self) -> Self {
+        Self {
+            lower : (&self.lower).min(&other.lower).clone(),
+            upper : (&self.upper).max(&other.upper).clone()
+        }
+    }
+
+    /// Returns the interval of every real number.
+    fn entire() -> Self {
+        Self::from_floats(f64::NEG_INFINITY,f64::INFINITY)
+    }
+
+    fn from_floats(lower : f64,upper : f64) -> Self {
+        Self { lower : GenericNumber::Decimal(lower) , upper : GenericNumber::Decimal(upper) }
+    }
+
+    /// Bounds the interval by decimals that contain it.
+    fn float_bounds(&self) -> (f64,f64) {
+        (float_below(&self.lower),float_above(&self.upper))
+    }
+
+    /// Returns `true` if the interval contains zero.
+    fn straddles_zero(&self) -> bool {
+        self.contains(&GenericNumber::Integer(0))
+    }
+
+    /// Applies an operation to the endpoints of two intervals, rounding outward unless they are exact.
+    fn operate(self,operation : Operation,other : Self) -> Self {
+        if operation == Operation::Div && other.straddles_zero() {
+            return Self::entire();
+        }
+
+        if let Some(interval) = self.exact_operation(operation,&other) {
+            return interval;
+        }
+
+        let ((lhs_lower,lhs_upper),(rhs_lower,rhs_upper)) = (self.float_bounds(),other.float_bounds());
+        match operation {
+            Operation::Add => Self::from_floats((lhs_lower + rhs_lower).next_down(),(lhs_upper + rhs_upper).next_up()),
+            Operation::Sub => Self::from_floats((lhs_lower - rhs_upper).next_down(),(lhs_upper - rhs_lower).next_up()),
+            Operation::Mul => corners([lhs_lower,lhs_upper],[rhs_lower,rhs_upper],|x,y| match x == 0.0 || y == 0.0 {
+                // zero times an unbounded endpoint is zero, as every number in the interval is finite
+                true => 0.0,
+                false => x * y
+            },1),
+            Operation::Div => corners([lhs_lower,lhs_upper],[rhs_lower,rhs_upper],|x,y| x / y,1),
+            Operation::Rem => unreachable!("intervals have no remainder")
+        }
+    }
+
+    /// Applies an operation to the endpoints of two intervals exactly, returning `None` if any endpoint or result is not exact.
+    fn exact_operation(&self,operation : Operation,other : &Self) -> Option<Self> {
+        let results = match operation {
+            Operation::Add => vec![exact(operation,&self.lower,&other.lower)?,exact(operation,&self.upper,&other.upper)?],
+            Operation::Sub => vec![exact(operation,&self.lower,&other.upper)?,exact(operation,&self.upper,&other.lower)?],
+            _ => vec![
+                exact(operation,&self.lower,&other.lower)?,
+                exact(operation,&self.lower,&other.upper)?,
+                exact(operation,&self.upper,&other.lower)?,
+                exact(operation,&self.upper,&other.upper)?,
+            ]
+        };
+
+        let lower = results.iter().min().expect("every operation has results").clone();
+        let upper = results.iter().max().expect("every operation has results").clone();
+        Some(Self { lower , upper })
+    }
+
+    /// Applies a function of one variable, using its shape to find the extremes over the interval.
+    pub(crate) fn apply(self,function : impl Fn(f64) -> f64,shape : Shape) -> Self {
+        let (lower,upper) = self.float_bounds();
+
+        let (min,max) = match shape {
+            Shape::Increasing => (function(lower),function(upper)),
+            Shape::Decreasing => (function(upper),function(lower)),
+            Shape::Even if lower <= 0.0 && 0.0 <= upper => (function(0.0),function(lower).max(function(upper))),
+            Shape::Even if upper < 0.0 => (function(upper),function(lower)),
+            Shape::Even => (function(lower),function(upper)),
+            Shape::Periodic { .. } if upper - lower >= TAU => (-1.0,1.0),
+            Shape::Periodic { peak } => {
+                let (at_lower,at_upper) = (function(lower),function(upper));
+                let min = match reaches(lower,upper,peak + PI,TAU) {
+                    true => -1.0,
+                    false => at_lower.min(at_upper)
+                };
+                let max = match reaches(lower,upper,peak,TAU) {
+                    true => 1.0,
+                    false => at_lower.max(at_upper)
+                };
+                (min,max)
+            },
+            Shape::Tangent if upper - lower >= PI || reaches(lower,upper,PI / 2.0,PI) => return Self::entire(),
+            Shape::Tangent => (function(lower),function(upper))
+        };
+
+        let (lower,upper) = (min.next_down().next_down(),max.next_up().next_up());
+        match shape {
+            // sine and cosine never leave [-1, 1], however they are rounded
+            Shape::Periodic { .. } => Self::from_floats(lower.max(-1.0),upper.min(1.0)),
+            _ => Self::from_floats(lower,upper)
+        }
+    }
+}
+
+/// Returns `true` if `[lower, upper]` reaches a point of `start + k * period` for some integer `k`.
+///
+/// Points within a few units in the last place of the interval are counted as reached, so the bounds stay guaranteed.
+fn reaches(lower : f64,upper : f64,start : f64,period : f64) -> bool {
+    let tolerance = (lower.abs().max(upper.abs()) + period) * 8.0 * f64::EPSILON;
+    let k = ((lower - tolerance - start) / period).ceil();
+    start + k * period <= upper + tolerance
+}
+
+/// Bounds the results of an operation at every pair of endpoints, widening them by `ulps` units in the last place.
+fn corners<T : FractionInteger>(lhs : [f64;2],rhs : [f64;2],operation : impl Fn(f64,f64) -> f64,ulps : usize) -> GenericInterval<T> {
+    let results = [operation(lhs[0],rhs[0]),operation(lhs[0],rhs[1]),operation(lhs[1],rhs[0]),operation(lhs[1],rhs[1])];
+
+    if results.iter().any(|result| result.is_nan()) {
+        return GenericInterval::from_floats(f64::NAN,f64::NAN);
+    }
+
+    let (mut lower,mut upper) = (results.into_iter().fold(f64::INFINITY,f64::min),results.into_iter().fold(f64::NEG_INFINITY,f64::max));
+    for _ in 0..ulps {
+        (lower,upper) = (lower.next_down(),upper.next_up());
+    }
+
+    GenericInterval::from_floats(lower,upper)
+}
+
+/// Applies an operation to exact operands, returning `None` if either operand or the result is not exact.
+fn exact<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Option<GenericNumber<T>> {
+    if !is_exact(lhs) || !is_exact(rhs) {
+        return None;
+    }
+
+    checked_operate(operation,lhs,rhs,PromotionPolicy::PreferExact).ok().filter(is_exact)
+}
+
+/// Returns the nearest `f64` to the number.
+fn nearest_float<T : FractionInteger>(number : &GenericNumber<T>) -> f64 {
+    match number.to_f64_exact() {
+        Ok(float) => float,
+        Err(lossy) => lossy.approximation()
+    }
+}
+
+/// Returns the largest `f64` that is not above the number.
+fn float_below<T : FractionInteger>(number : &GenericNumber<T>) -> f64 {
+    let nearest = nearest_float(number);
+    match GenericNumber::Decimal(nearest) > *number {
+        true => nearest.next_down(),
+        false => nearest
+    }
+}
+
+/// Returns the smallest `f64` that is not below the number.
+fn float_above<T : FractionInteger>(number : &GenericNumber<T>) -> f64 {
+    let nearest = nearest_float(number);
+    match GenericNumber::Decimal(nearest) < *number {
+        true => nearest.next_up(),
+        false => nearest
+    }
+}
+
+impl<T : FractionInteger> From<GenericNumber<T>> for GenericInterval<T> {
+    fn from(value: GenericNumber<T>) -> Self {
+        Self { lower : value.clone() , upper : value }
+    }
+}
+
+impl<T : FractionInteger> std::fmt::Display for GenericInterval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f,"[{}, {}]",self.lower,self.upper)
+    }
+}
+
+impl<T : FractionInteger> Neg for GenericInterval<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { lower : -self.upper , upper : -self.lower }
+    }
+}
+
+macro_rules! interval_operations {
+    ($($trait : ident => $fn : ident => $operation : ident),*) => {
+        $(
+            impl<T : FractionInteger> $trait for GenericInterval<T> {
+                type Output = Self;
+
+                fn $fn(self, other: Self) -> Self {
+                    self.operate(Operation::$operation,other)
+                }
+            }
+
+            impl<T : FractionInteger> $trait<GenericNumber<T>> for GenericInterval<T> {
+                type Output = Self;
+
+                fn $fn(self, other: GenericNumber<T>) -> Self {
+                    self.operate(Operation::$operation,GenericInterval::from(other))
+                }
+            }
+
+            impl<T : FractionInteger> $trait<GenericInterval<T>> for GenericNumber<T> {
+                type Output = GenericInterval<T>;
+
+                fn $fn(self, other: GenericInterval<T>) -> GenericInterval<T> {
+                    GenericInterval::from(self).operate(Operation::$operation,other)
+                }
+            }
+        )*
+    };
+}
+
+interval_operations!(
+    Add => add => Add,
+    Sub => sub => Sub,
+    Mul => mul => Mul,
+    Div => div => Div
+);
+
+/// Raises every number of the interval to every power in `exponent`.
+///
+/// Negative bases are only defined for integer powers, and otherwise give NaN endpoints.
+#[cfg(feature="num")]
+impl<T : FractionInteger> Pow<GenericInterval<T>> for GenericInterval<T> {
+    type Output = Self;
+
+    fn pow(self, exponent: Self) -> Self {
+        let ((lower,upper),(exponent_lower,exponent_upper)) = (self.float_bounds(),exponent.float_bounds());
+
+        if lower >= 0.0 {
+            // the power is monotonic in both the base and the exponent, so its extremes lie at the corners
+            return corners([lower,upper],[exponent_lower,exponent_upper],f64::powf,2);
+        }
+
+        let integer = exponent_lower == exponent_upper && exponent_lower.fract() == 0.0;
+        match exponent_lower {
+            _ if !integer => GenericInterval::from_floats(f64::NAN,f64::NAN),
+            0.0 => GenericInterval::from(GenericNumber::Integer(1)),
+            power if power < 0.0 && self.straddles_zero() => GenericInterval::entire(),
+            // even powers fall to zero between negative and positive bases
+            power if power % 2.0 == 0.0 && self.straddles_zero() => {
+                let max = lower.abs().max(upper.abs()).powf(power);
+                GenericInterval::from_floats(0.0,max.next_up().next_up())
+            },
+            power => corners([lower,upper],[power,power],f64::powf,2)
+        }
+    }
+}
+
+#[cfg(feature="num")]
+impl<T : FractionInteger> Pow<GenericNumber<T>> for GenericInterval<T> {
+    type Output = Self;
+
+    fn pow(self, exponent: GenericNumber<T>) -> Self {
+        self.pow(GenericInterval::from(exponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fraction::GenericFraction;
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn interval(lower : f64,upper : f64) -> Interval {
+        Interval::new(Number::Decimal(lower),Number::Decimal(upper))
+    }
+
+    #[test]
+    fn test_exact_endpoints_stay_exact() {
+        let third = Interval::new(Number::Fraction(GenericFraction::new(1u32,3u32)),Number::Integer(1));
+        let sum = third.clone() + Number::Integer(1);
+        assert!(sum.lower().structural_eq(&Number::Fraction(GenericFraction::new(4u32,3u32))));
+        assert!(sum.upper().structural_eq(&Number::Integer(2)));
+
+        let product = third * Interval::new(Number::Integer(-3),Number::Integer(3));
+        assert!(product.lower().structural_eq(&Number::Integer(-3)));
+        assert!(product.upper().structural_eq(&Number::Integer(3)));
+    }
+
+    #[test]
+    fn test_rounds_outward() {
+        let tenth = Interval::from(Number::StandardForm(StandardForm::new(1.0,-1)));
+        let sum = tenth.clone() + tenth.clone() + tenth;
+
+        // 0.1 + 0.1 + 0.1 is not 0.3 in floating point, but the exact sum lies within the bounds
+        let three_tenths = Number::Fraction(GenericFraction::new(3u32,10u32));
+        assert!(sum.contains(&three_tenths));
+        assert!(f64::from(sum.width()) < 1e-15);
+
+        let third = interval(1.0,1.0) / interval(3.0,3.0);
+        assert!(third.contains(&Number::Fraction(GenericFraction::new(1u32,3u32))));
+    }
+
+    #[test]
+    fn test_fraction_operands_round_outward() {
+        // 0.30000000000000004 is exactly 1351079888211149/2^52, so 3/10 minus it is exactly -1/(5*2^52)
+        let three_tenths = GenericInterval::<u64>::from(GenericNumber::Fraction(GenericFraction::new(3u64,10u64)));
+        let difference = three_tenths - GenericInterval::from(GenericNumber::Decimal(0.30000000000000004));
+
+        assert!(difference.contains(&GenericNumber::Fraction(GenericFraction::new_neg(1u64,5u64 << 52))));
+        assert!(f64::from(difference.width()) < 1e-16);
+    }
+
+    #[test]
+    fn test_division() {
+        let quotient = interval(1.0,2.0) / interval(-1.0,4.0);
+        assert_eq!(*quotient.lower(),f64::NEG_INFINITY);
+        assert_eq!(*quotient.upper(),f64::INFINITY);
+
+        let quotient = interval(1.0,2.0) / interval(4.0,8.0);
+        assert!(quotient.contains(&Number::Decimal(0.125)) && quotient.contains(&Number::Decimal(0.5)));
+    }
+
+    #[test]
+    fn test_functions() {
+        let around_peak = interval(1.0,2.0).sin();
+        assert_eq!(*around_peak.upper(),1.0);
+        assert!(around_peak.contains(&Number::Decimal(2_f64.sin())));
+
+        let cosine = interval(-0.5,0.5).cosh();
+        assert!(cosine.contains(&Number::Decimal(1.0)) && cosine.contains(&Number::Decimal(0.5_f64.cosh())));
+        assert!(*cosine.lower() <= 1.0 && *cosine.lower() > 0.999);
+
+        let tangent = interval(1.0,2.0).tan();
+        assert_eq!(*tangent.lower(),f64::NEG_INFINITY);
+
+        let arccosine = interval(-1.0,1.0).acos();
+        assert!(arccosine.contains(&Number::Decimal(0.0)) && arccosine.contains(&Number::Decimal(PI)));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let (a,b) = (interval(0.0,2.0),Interval::new(Number::Integer(1),Number::Integer(3)));
+
+        let both = a.intersect(&b).unwrap();
+        assert!(both.lower().structural_eq(&Number::Integer(1)) && both.upper().structural_eq(&Number::Decimal(2.0)));
+        assert!(a.intersect(&interval(5.0,6.0)).is_none());
+
+        let hull = a.hull(&b);
+        assert!(hull.lower().structural_eq(&Number::Decimal(0.0)) && hull.upper().structural_eq(&Number::Integer(3)));
+
+        assert_eq!(b.width(),2);
+        assert_eq!(b.midpoint(),2);
+        assert_eq!(interval(0.0,1.0).midpoint(),0.5);
+        assert!(b.contains(&Number::Fraction(GenericFraction::new(5u32,2u32))));
+        assert!(!b.contains(&Number::Decimal(3.5)));
+    }
+
+    #[cfg(feature="num")]
+    #[test]
+    fn test_pow() {
+        let squared = interval(-2.0,3.0).pow(Number::Integer(2));
+        assert_eq!(*squared.lower(),0.0);
+        assert!(squared.contains(&Number::Decimal(9.0)));
+
+        let root = interval(4.0,9.0).pow(Number::Decimal(0.5));
+        assert!(root.contains(&Number::Decimal(2.0)) && root.contains(&Number::Decimal(3.0)));
+
+        assert!(interval(-2.0,3.0).pow(Number::Decimal(0.5)).lower().is_nan());
+    }
+}
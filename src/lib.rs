@@ -19,9 +19,36 @@ pub use fraction;
 
 mod number;
 mod err;
+mod cmp;
+mod special;
+mod promotion;
+mod checked;
+mod overflow;
+mod round;
+mod measurement;
+mod uncertain;
+mod interval;
+mod complex;
+mod root;
+mod power;
+mod exponential;
+mod angle;
+mod symbolic;
+mod repeating;
+mod mixed;
+mod unicode;
 
 pub use number::*;
 pub use err::*;
+pub use promotion::PromotionPolicy;
+pub use round::RoundingMode;
+pub use measurement::{GenericMeasurement,Measurement};
+pub use uncertain::{GenericUncertain,Uncertain};
+pub use interval::{GenericInterval,Interval};
+pub use complex::{GenericComplexNumber,ComplexNumber};
+pub use root::Surd;
+pub use angle::{AngleUnit,GenericAngle,Angle};
+pub use symbolic::{GenericSymbolic,Symbolic};
 
 #[cfg(feature = "num")]
 mod num;
@@ -42,4 +69,7 @@ pub use self::hash::*;
 mod nom;
 
 #[cfg(feature = "nom")]
-pub use nom::*;
\ No newline at end of file
+pub use nom::*;
+
+#[cfg(feature = "bigint")]
+mod bigint;
\ No newline at end of file
@@ -0,0 +1,267 @@
+use std::ops::{Add,Sub,Mul,Div,Neg};
+
+use crate::{GenericNumber,FractionInteger,RoundingMode};
+use crate::round::leading_exponent;
+
+/// A measured quantity, which carries how precisely it is known through arithmetic.
+///
+/// The precision is the decimal place of the last significant digit. Sums and differences are as precise
+/// as the least precise decimal place of their operands, while products and quotients have as many
+/// significant figures as the operand with the fewest. The value itself is never rounded, so rounding
+/// errors do not build up, and is only rounded with `RoundingMode::HalfEven` when it is displayed.
+///
+/// Plain numbers in arithmetic with a measurement are exact, like counts and defined constants, so they do not limit its precision.
+#[derive(Debug,Clone)]
+pub struct GenericMeasurement<T : FractionInteger> {
+    value : GenericNumber<T>,
+    decimal_places : i32
+}
+
+/// A measurement whose fractions have a `u32` numerator and denominator.
+pub type Measurement = GenericMeasurement<u32>;
+
+/// Which precision of a measurement arithmetic with an exact number keeps.
+#[derive(Debug,Clone,Copy)]
+enum Kept {
+    DecimalPlaces,
+    SignificantFigures
+}
+
+impl<T : FractionInteger> GenericMeasurement<T> {
+    /// Creates a measurement of `value` to `significant_figures` significant figures.
+    ///
+    /// A value of zero is taken to have its significant figures after the decimal point, so zero to three figures is `0.00`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `significant_figures` is zero.
+    pub fn new(value : GenericNumber<T>,significant_figures : u32) -> Self {
+        assert!(significant_figures > 0,"a measurement has at least one significant figure");
+
+        let figures = i32::try_from(significant_figures).unwrap_or(i32::MAX);
+        let decimal_places = figures.saturating_sub(1).saturating_sub(leading_exponent(&value).unwrap_or(0));
+        Self { value , decimal_places }
+    }
+
+    /// Creates a measurement of `value` whose last significant digit is `decimal_places` digits after the decimal point.
+    ///
+    /// A negative number of decimal places is a measurement to the nearest ten, hundred and so on.
+    pub fn with_decimal_places(value : GenericNumber<T>,decimal_places : i32) -> Self {
+        Self { value , decimal_places }
+    }
+
+    /// Retrieves the unrounded value of the measurement.
+    pub fn value(&self) -> &GenericNumber<T> {
+        &self.value
+    }
+
+    /// Retrieves the decimal place of the last significant digit, counted after the decimal point.
+    pub fn decimal_places(&self) -> i32 {
+        self.decimal_places
+    }
+
+    /// Returns the number of significant figures of the measurement, which is zero if its value rounds to zero.
+    pub fn significant_figures(&self) -> u32 {
+        match leading_exponent(&self.rounded()) {
+            Some(exponent) => (exponent as i64 + self.decimal_places as i64 + 1).clamp(0,u32::MAX as i64) as u32,
+            None => 0
+        }
+    }
+
+    /// Returns the value of the measurement rounded to its significant figures.
+    pub fn rounded(&self) -> GenericNumber<T> {
+        self.value.round_to(self.decimal_places,RoundingMode::HalfEven)
+    }
+
+    /// Holds the result of arithmetic between the measurement and an exact number, which keeps the precision of the measurement.
+    fn with_exact_operand(&self,value : GenericNumber<T>,kept : Kept) -> Self {
+        match kept {
+            Kept::DecimalPlaces => Self::with_decimal_places(value,self.decimal_places),
+            Kept::SignificantFigures => Self::with_figures(value,self.significant_figures())
+        }
+    }
+
+    /// Keeps the significant figures of the least precise measurement for the exact result of a product or quotient.
+    fn with_figures(value : GenericNumber<T>,significant_figures : u32) -> Self {
+        match significant_figures {
+            0 => Self::with_decimal_places(value,i32::MIN),
+            figures => Self::new(value,figures)
+        }
+    }
+}
+
+impl<T : FractionInteger> std::fmt::Display for GenericMeasurement<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let rounded = self.rounded();
+        if !rounded.is_finite() {
+            return write!(f,"{rounded}");
+        }
+
+        let figures = self.significant_figures() as usize;
+        match &rounded {
+            // like standard form, large exponents are written in scientific notation
+            GenericNumber::StandardForm(sf) if *sf.exponent() > 4 && figures > 0 => write!(f,"{:.*}e{}",figures - 1,sf.mantissa(),sf.exponent()),
+            _ if figures > 0 => write!(f,"{}",significant_digits(f64::from(rounded),figures,self.decimal_places)),
+            _ => write!(f,"{:.*}",self.decimal_places.max(0) as usize,f64::from(rounded))
+        }
+    }
+}
+
+/// Writes a rounded value with `decimal_places` digits after the decimal point, from only its first `figures` digits,
+/// so the digits that are not significant are zeros rather than those of the nearest float.
+fn significant_digits(float : f64,figures : usize,decimal_places : i32) -> String {
+    let scientific = format!("{:.*e}",figures - 1,float.abs());
+    let (mantissa,_) = scientific.split_once('e').expect("floats format with an exponent");
+    let digits = mantissa.replace('.',"");
+    let sign = if float.is_sign_negative() { "-" } else { "" };
+
+    match usize::try_from(decimal_places) {
+        Ok(places) => {
+            let digits = format!("{digits:0>width$}",width = places + 1);
+            let (whole,decimals) = digits.split_at(digits.len() - places);
+            match places {
+                0 => format!("{sign}{whole}"),
+                _ => format!("{sign}{whole}.{decimals}")
+            }
+        },
+        Err(_) => format!("{sign}{digits}{}","0".repeat(decimal_places.unsigned_abs() as usize))
+    }
+}
+
+impl<T : FractionInteger> Neg for GenericMeasurement<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::with_decimal_places(-self.value,self.decimal_places)
+    }
+}
+
+impl<T : FractionInteger> Add for GenericMeasurement<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::with_decimal_places(self.value + other.value,self.decimal_places.min(other.decimal_places))
+    }
+}
+
+impl<T : FractionInteger> Sub for GenericMeasurement<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::with_decimal_places(self.value - other.value,self.decimal_places.min(other.decimal_places))
+    }
+}
+
+impl<T : FractionInteger> Mul for GenericMeasurement<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let figures = self.significant_figures().min(other.significant_figures());
+        Self::with_figures(self.value * other.value,figures)
+    }
+}
+
+impl<T : FractionInteger> Div for GenericMeasurement<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let figures = self.significant_figures().min(other.significant_figures());
+        Self::with_figures(self.value / other.value,figures)
+    }
+}
+
+macro_rules! exact_operand {
+    ($($trait : ident => $fn : ident keeps $kept : ident),*) => {
+        $(
+            impl<T : FractionInteger> $trait<GenericNumber<T>> for GenericMeasurement<T> {
+                type Output = Self;
+
+                fn $fn(self, other: GenericNumber<T>) -> Self {
+                    let value = self.value.clone().$fn(other);
+                    self.with_exact_operand(value,Kept::$kept)
+                }
+            }
+
+            impl<T : FractionInteger> $trait<GenericMeasurement<T>> for GenericNumber<T> {
+                type Output = GenericMeasurement<T>;
+
+                fn $fn(self, other: GenericMeasurement<T>) -> GenericMeasurement<T> {
+                    let value = self.$fn(other.value.clone());
+                    other.with_exact_operand(value,Kept::$kept)
+                }
+            }
+        )*
+    };
+}
+
+exact_operand!(
+    Add => add keeps DecimalPlaces,
+    Sub => sub keeps DecimalPlaces,
+    Mul => mul keeps SignificantFigures,
+    Div => div keeps SignificantFigures
+);
+
+#[cfg(test)]
+mod tests {
+    use fraction::GenericFraction;
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    #[test]
+    fn test_products_keep_the_fewest_significant_figures() {
+        let length = Measurement::new(Number::StandardForm(StandardForm::new(1.2,3)),2);
+        let width = Measurement::new(Number::Decimal(3.24159),6);
+
+        let area = length.clone() * width;
+        assert_eq!(area.significant_figures(),2);
+        assert_eq!(area.to_string(),"3900");
+
+        let ratio = Measurement::new(Number::Decimal(9.0),3) / Measurement::new(Number::Decimal(7.0),2);
+        assert_eq!(ratio.to_string(),"1.3");
+
+        // the unrounded value is kept, so the rounding errors of intermediate results do not build up
+        assert!(area.value().structural_eq(&(Number::StandardForm(StandardForm::new(1.2,3)) * Number::Decimal(3.24159))));
+    }
+
+    #[test]
+    fn test_sums_keep_the_least_precise_decimal_place() {
+        let sum = Measurement::new(Number::Decimal(12.11),4) + Measurement::new(Number::Decimal(0.3),1);
+        assert_eq!(sum.decimal_places(),1);
+        assert_eq!(sum.to_string(),"12.4");
+
+        // cancellation loses significant figures
+        let difference = Measurement::new(Number::Decimal(1.234),4) - Measurement::new(Number::Decimal(1.2),2);
+        assert_eq!(difference.significant_figures(),0);
+        assert_eq!(difference.to_string(),"0.0");
+
+        let hundreds = Measurement::with_decimal_places(Number::Integer(1234),-2) + Measurement::new(Number::Decimal(5.5),2);
+        assert_eq!(hundreds.to_string(),"1200");
+    }
+
+    #[test]
+    fn test_exact_operands() {
+        let mass = Measurement::new(Number::Decimal(2.0),2);
+        let doubled = mass.clone() * Number::Integer(3);
+        assert_eq!(doubled.to_string(),"6.0");
+
+        let shifted = Number::Fraction(GenericFraction::new(1u32,3u32)) + mass;
+        assert_eq!(shifted.to_string(),"2.3");
+    }
+
+    #[test]
+    fn test_display_keeps_trailing_zeros() {
+        assert_eq!(Measurement::new(Number::Decimal(2.0),3).to_string(),"2.00");
+        assert_eq!(Measurement::new(Number::Fraction(GenericFraction::new(1u32,3u32)),3).to_string(),"0.333");
+        assert_eq!(Measurement::new(Number::StandardForm(StandardForm::new(6.02214076,23)),4).to_string(),"6.022e23");
+        // the last significant digit stays in the same place when rounding carries into a new digit
+        assert_eq!(Measurement::new(Number::StandardForm(StandardForm::new(9.99,7)),2).to_string(),"1.00e8");
+        assert_eq!(Measurement::new(Number::Decimal(0.0),3).to_string(),"0.00");
+        assert_eq!(Measurement::new(Number::Decimal(1.2e30),2).to_string(),"1200000000000000000000000000000");
+        assert_eq!(Measurement::new(Number::Decimal(-1.2e30),2).to_string(),"-1200000000000000000000000000000");
+        assert_eq!(Measurement::new(Number::Integer(123_456_789_012_345_678_901_234),3).to_string(),"123000000000000000000000");
+        assert_eq!(Measurement::new(Number::Decimal(0.00123),2).to_string(),"0.0012");
+    }
+}
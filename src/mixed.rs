@@ -0,0 +1,113 @@
+use fraction::{GenericFraction,Integer};
+
+use crate::{GenericNumber,FractionInteger};
+use crate::number::to_u128_fraction;
+use crate::unicode::vulgar_fraction;
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Writes the number with fractions as a mixed number, a whole part followed by a proper fraction,
+    /// so `11/4` is `2 3/4` and `-3/2` is `-1 1/2`. Proper fractions and other numbers are written as usual.
+    pub fn to_mixed_number(&self) -> String {
+        let parts = match self {
+            GenericNumber::Fraction(fr) => match to_u128_fraction(fr) {
+                Some(GenericFraction::Rational(sign,ratio)) => Some((sign.is_negative(),mixed_parts(*ratio.numer(),*ratio.denom()))),
+                _ => None
+            },
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(GenericFraction::Rational(sign,ratio)) => Some((sign.is_negative(),mixed_parts(ratio.numer().clone(),ratio.denom().clone()))),
+            _ => None
+        };
+
+        match parts {
+            Some((negative,(whole,Some(fraction)))) => {
+                let sign = if negative { "-" } else { "" };
+                match whole.as_str() {
+                    "0" => format!("{sign}{fraction}"),
+                    whole => format!("{sign}{whole} {fraction}")
+                }
+            },
+            _ => self.to_string()
+        }
+    }
+}
+
+/// Splits `numer/denom` into its whole part and the proper fraction left over, if any.
+fn mixed_parts<N : Integer + std::fmt::Display>(numer : N,denom : N) -> (String,Option<String>) {
+    let (whole,remainder) = numer.div_rem(&denom);
+    match remainder.is_zero() {
+        true => (whole.to_string(),None),
+        false => (whole.to_string(),Some(format!("{remainder}/{denom}")))
+    }
+}
+
+/// Builds the exact value of a mixed number from its whole part and its proper fraction.
+///
+/// Returns `None` if a part is not made of ASCII digits or does not fit into an `i128`, or the fraction is not proper.
+pub(crate) fn from_mixed_parts<T : FractionInteger>(negative : bool,whole : &str,numer : &str,denom : &str) -> Option<GenericNumber<T>> {
+    let parse = |digits : &str| digits.bytes().all(|byte| byte.is_ascii_digit()).then(|| digits.parse::<i128>().ok())?;
+    let (whole,numer,denom) = (parse(whole)?,parse(numer)?,parse(denom)?);
+    if numer >= denom {
+        return None;
+    }
+
+    let value = GenericNumber::Integer(whole) + GenericNumber::Integer(numer) / GenericNumber::Integer(denom);
+    Some(match negative {
+        true => -value,
+        false => value
+    })
+}
+
+/// Parses a mixed number written as `2 3/4`, `-1 1/2` or with a vulgar fraction as in `2¾`.
+pub(crate) fn parse_mixed<T : FractionInteger>(value : &str) -> Option<GenericNumber<T>> {
+    let value = value.trim();
+    let (negative,rest) = match value.strip_prefix('-') {
+        Some(rest) => (true,rest),
+        None => (false,value.strip_prefix('+').unwrap_or(value))
+    };
+
+    let mut chars = rest.chars();
+    if let Some((numer,denom)) = chars.next_back().and_then(vulgar_fraction) {
+        return from_mixed_parts(negative,chars.as_str().trim_end(),&numer.to_string(),&denom.to_string());
+    }
+
+    let (whole,fraction) = rest.split_once(char::is_whitespace)?;
+    let (numer,denom) = fraction.trim_start().split_once('/')?;
+    from_mixed_parts(negative,whole,numer,denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Number;
+
+    use super::*;
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_parsing() {
+        assert!(Number::try_from("2 3/4").unwrap().structural_eq(&fraction(11,4)));
+        assert!(Number::try_from("-1 1/2").unwrap().structural_eq(&-fraction(3,2)));
+        assert!(Number::try_from("2¾").unwrap().structural_eq(&fraction(11,4)));
+        assert!(Number::try_from("-3 ⅓").unwrap().structural_eq(&-fraction(10,3)));
+
+        assert!(Number::try_from("2 5/4").is_err());
+        assert!(Number::try_from("2 3/0").is_err());
+        assert!(Number::try_from("2 -3/4").is_err());
+        assert!(Number::try_from("x¾").is_err());
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(fraction(11,4).to_mixed_number(),"2 3/4");
+        assert_eq!((-fraction(3,2)).to_mixed_number(),"-1 1/2");
+        assert_eq!(fraction(3,4).to_mixed_number(),"3/4");
+        assert_eq!((-fraction(3,4)).to_mixed_number(),"-3/4");
+        assert_eq!(fraction(8,4).to_mixed_number(),"2");
+        assert_eq!(Number::Decimal(2.75).to_mixed_number(),"2.75");
+
+        let mixed = fraction(22,7).to_mixed_number();
+        assert!(Number::try_from(mixed.as_str()).unwrap().structural_eq(&fraction(22,7)));
+    }
+}
@@ -1,41 +1,174 @@
-use fraction::GenericFraction;
-use standardform::parse_standard_form_with_required_exponent;
+use std::str::FromStr;
+
+use fraction::{GenericFraction, Integer};
+use standardform::{StandardForm, parse_standard_form_with_required_exponent};
 
 use nom::{
     IResult,
     number::complete::double, 
     branch::alt, 
-    combinator::{map, opt, map_res},
-    character::complete::{char, digit1}, sequence::{pair, preceded},
+    bytes::complete::tag,
+    combinator::{map, map_opt, opt, map_res, not, recognize},
+    character::complete::{anychar, char, digit1, one_of, satisfy, space0, space1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    multi::{many0, many1},
 };
 
-use crate::Number;
+use crate::{Number,GenericNumber,FractionInteger};
+use crate::repeating::{OVERLINE, from_repeating_parts, split_ellipsis};
+use crate::mixed::from_mixed_parts;
+use crate::unicode::{FRACTION_SLASH, MULTIPLICATION_SIGNS, superscript_digit, superscript_sign, vulgar_fraction};
 
 
 /// Parses a numeric input string and returns a `Number` enum.
 ///
 /// This function attempts to parse the input string into different numeric formats
 /// and returns a `Number` enum variant based on the successful parsing result.
-/// Supported formats include floating-point decimals, standard form numbers, and fractions.
+/// Supported formats include integers, floating-point decimals, standard form numbers, and fractions,
+/// along with mixed numbers, repeating decimals and Unicode notation such as `½` or `1.2×10⁻³`.
 ///
 /// # Arguments
 ///
 /// * `input` - The input string to parse.
 pub fn parse_number(input : &str) -> IResult<&str,Number> {
+    parse_generic_number(input)
+}
+
+/// Parses a numeric input string into a `GenericNumber` whose fractions have a numerator and denominator of type `T`.
+///
+/// This behaves like `parse_number`, except that fractions whose numerator or denominator do not fit
+/// into `T` are not parsed as fractions.
+pub fn parse_generic_number<T : FractionInteger>(input : &str) -> IResult<&str,GenericNumber<T>> {
     alt((
-        map(parse_fraction,Number::Fraction),
-        map(parse_standard_form_with_required_exponent,Number::StandardForm),
-        map(double,Number::Decimal),
+        parse_mixed_number,
+        parse_vulgar_fraction,
+        parse_repeating_decimal,
+        parse_fraction_number,
+        map(parse_standard_form_with_required_exponent,GenericNumber::StandardForm),
+        map(parse_unicode_standard_form,GenericNumber::StandardForm),
+        map(parse_integer,GenericNumber::Integer),
+        map(double,GenericNumber::Decimal),
     ))(input)
 }
 
-fn parse_fraction(input : &str) -> IResult<&str,GenericFraction<u32>> {
+#[cfg(not(feature="bigint"))]
+fn parse_fraction_number<T : FractionInteger>(input : &str) -> IResult<&str,GenericNumber<T>> {
+    map(parse_fraction,GenericNumber::Fraction)(input)
+}
+
+/// Parses a fraction, holding it in `Number::BigFraction` if it does not fit into `Number::Fraction`.
+#[cfg(feature="bigint")]
+fn parse_fraction_number<T : FractionInteger>(input : &str) -> IResult<&str,GenericNumber<T>> {
+    map(parse_fraction,crate::bigint::narrow)(input)
+}
+
+/// Parses a `/` or the fraction slash `⁄` between a numerator and a denominator.
+fn fraction_slash(input : &str) -> IResult<&str,char> {
+    alt((char('/'),char(FRACTION_SLASH)))(input)
+}
+
+/// Parses a Unicode vulgar fraction such as `½`, optionally preceded by a sign.
+fn parse_vulgar_fraction<T : FractionInteger>(input : &str) -> IResult<&str,GenericNumber<T>> {
+    let (rest,(sign,(numer,denom))) = pair(opt(one_of("+-")),map_opt(anychar,vulgar_fraction))(input)?;
+    match from_mixed_parts(sign == Some('-'),"0",&numer.to_string(),&denom.to_string()) {
+        Some(number) => Ok((rest,number)),
+        None => Err(nom::Err::Error(nom::error::Error::new(input,nom::error::ErrorKind::Verify)))
+    }
+}
+
+/// Parses a number in standard form whose power of ten follows `×`, `·` or `*` and has its exponent
+/// in superscript or after `^`, such as `1.2×10⁻³` or `1.2·10^-3`.
+fn parse_unicode_standard_form(input : &str) -> IResult<&str,StandardForm> {
+    let superscript = map_opt(
+        pair(opt(map_opt(anychar,superscript_sign)),many1(map_opt(anychar,superscript_digit))),
+        |(sign,digits)| {
+            let exponent = digits.into_iter().try_fold(0_i8,|exponent,digit| exponent.checked_mul(10)?.checked_add(digit as i8))?;
+            Some(if sign == Some('-') { -exponent } else { exponent })
+        }
+    );
+    let ascii = preceded(char('^'),map_res(recognize(pair(opt(one_of("+-")),digit1)),str::parse::<i8>));
+
+    let (rest,(mantissa,_,_,exponent)) = tuple((double,one_of(MULTIPLICATION_SIGNS.as_slice()),tag("10"),alt((superscript,ascii))))(input)?;
+
+    match in_standard_form_range(mantissa,exponent) {
+        true => Ok((rest,StandardForm::new(mantissa,exponent))),
+        false => Err(nom::Err::Error(nom::error::Error::new(input,nom::error::ErrorKind::Verify)))
+    }
+}
+
+/// Whether a mantissa and exponent stay within the range of standard form once normalized,
+/// which `StandardForm::new` would otherwise panic on.
+fn in_standard_form_range(mantissa : f64,exponent : i8) -> bool {
+    if !mantissa.is_finite() { return false }
+    if mantissa == 0.0 { return true }
+
+    // the exponent of the shortest decimal that reads back as the mantissa
+    let written = format!("{mantissa:e}");
+    let shift = written.split_once('e').and_then(|(_,shift)| shift.parse::<i32>().ok()).unwrap_or(0);
+    i8::try_from(exponent as i32 + shift).is_ok()
+}
+
+/// Parses a mixed number written as `2 3/4`, `-1 1/2` or with a vulgar fraction as in `+2¾`,
+/// which is held exactly as a fraction.
+fn parse_mixed_number<T : FractionInteger>(input : &str) -> IResult<&str,GenericNumber<T>> {
+    let (rest,(sign,whole,(numer,denom))) = tuple((
+        opt(one_of("+-")),
+        digit1,
+        alt((
+            map(preceded(space0,map_opt(anychar,vulgar_fraction)),|(numer,denom)| (numer.to_string(),denom.to_string())),
+            map(preceded(space1,separated_pair(digit1,fraction_slash,digit1)),|(numer,denom) : (&str,&str)| (numer.to_string(),denom.to_string()))
+        ))
+    ))(input)?;
+
+    match from_mixed_parts(sign == Some('-'),whole,&numer,&denom) {
+        Some(number) => Ok((rest,number)),
+        None => Err(nom::Err::Error(nom::error::Error::new(input,nom::error::ErrorKind::Verify)))
+    }
+}
+
+/// Parses a repeating decimal written as `0.1(6)`, `0.1666...` or with an overline above each repeating digit,
+/// which is held exactly as a fraction.
+fn parse_repeating_decimal<T : FractionInteger>(input : &str) -> IResult<&str,GenericNumber<T>> {
+    let digit = || satisfy(|c| c.is_ascii_digit());
+    let (rest,(sign,whole,_,fixed)) = tuple((
+        opt(char('-')),
+        digit1,
+        char('.'),
+        recognize(many0(terminated(digit(),not(char(OVERLINE)))))
+    ))(input)?;
+
+    let (rest,period) = alt((
+        map(delimited(char('('),digit1,char(')')),|period : &str| Some(period.to_string())),
+        map(many1(terminated(digit(),char(OVERLINE))),|digits| Some(digits.into_iter().collect())),
+        map(alt((tag("..."),tag("…"))),|_| None)
+    ))(rest)?;
+
+    let parts = match &period {
+        Some(period) => Some((fixed,period.as_str())),
+        None => split_ellipsis(fixed)
+    };
+
+    match parts.and_then(|(fixed,period)| from_repeating_parts(sign.is_some(),whole,fixed,period)) {
+        Some(number) => Ok((rest,number)),
+        None => Err(nom::Err::Error(nom::error::Error::new(input,nom::error::ErrorKind::Verify)))
+    }
+}
+
+/// Parses an integer that fits into an `i128` and is not the start of a decimal.
+fn parse_integer(input : &str) -> IResult<&str,i128> {
+    map_res(
+        terminated(recognize(pair(opt(char('-')),digit1)),not(one_of(".eE"))),
+        str::parse::<i128>
+    )(input)
+}
+
+fn parse_fraction<T : Clone + Integer + FromStr>(input : &str) -> IResult<&str,GenericFraction<T>> {
     let (input, sign) = opt(char('-'))(input)?;
     let (input, (numerator, denominator)) = pair(
-        map_res(digit1, str::parse::<u32>),
+        map_res(digit1, str::parse::<T>),
         preceded(
-            char('/'), 
-            map_res(digit1, str::parse::<u32>)
+            fraction_slash, 
+            map_res(digit1, str::parse::<T>)
         ),
     )(input)?;
     
@@ -55,28 +188,27 @@ mod parse_fraction_tests {
     fn test_parse_positive_fraction() {
         let input = "2/3";
         let expected_result = Ok(("",GenericFraction::new(2u32, 3u32)));
-        assert_eq!(parse_fraction(input), expected_result);
+        assert_eq!(parse_fraction::<u32>(input), expected_result);
     }
     
     #[test]
     fn test_parse_negative_fraction() {
         let input = "-4/5";
         let expected_result = Ok(("",(GenericFraction::new_neg(4u32, 5u32))));
-        assert_eq!(parse_fraction(input), expected_result);
+        assert_eq!(parse_fraction::<u32>(input), expected_result);
     }
     
     #[test]
     fn test_parse_invalid_input() {
         let input = "abc";
-        assert!(parse_fraction(input).is_err());
+        assert!(parse_fraction::<u32>(input).is_err());
     }
 }
 
 
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod tests {
-    use standardform::StandardForm;
-
     use super::*;
     
     #[test]
@@ -104,4 +236,72 @@ mod tests {
         let input = "abc";
         assert!(parse_number(input).is_err());
     }
+
+    #[test]
+    fn test_parse_integer() {
+        assert_eq!(parse_number("-12345678901234567890123"), Ok(("", Number::Integer(-12345678901234567890123))));
+        assert!(matches!(parse_number("12.5"), Ok(("", Number::Decimal(_)))));
+        assert!(matches!(parse_number("1e3"), Ok(("", Number::Decimal(_)))));
+    }
+
+    #[test]
+    fn test_parse_repeating_decimal() {
+        let sixth = Number::Fraction(GenericFraction::new(1u32, 6u32));
+        assert_eq!(parse_number("0.1(6) + 1"), Ok((" + 1", sixth.clone())));
+        assert_eq!(parse_number("0.1666..."), Ok(("", sixth.clone())));
+        assert_eq!(parse_number("0.16\u{305}"), Ok(("", sixth)));
+        assert_eq!(parse_number("-0.(3)"), Ok(("", Number::Fraction(GenericFraction::new_neg(1u32, 3u32)))));
+
+        // decimals without a period are parsed as before
+        assert_eq!(parse_number("0.16 + 1"), Ok((" + 1", Number::Decimal(0.16))));
+        assert_eq!(parse_number("0.123..."), Ok(("...", Number::Decimal(0.123))));
+    }
+
+    #[test]
+    fn test_parse_mixed_number() {
+        let eleven_quarters = Number::Fraction(GenericFraction::new(11u32, 4u32));
+        assert_eq!(parse_number("2 3/4"), Ok(("", eleven_quarters.clone())));
+        assert_eq!(parse_number("2¾ cups"), Ok((" cups", eleven_quarters.clone())));
+        assert_eq!(parse_number("+2¾"), Ok(("", eleven_quarters)));
+        assert_eq!(parse_number("-1 1/2"), Ok(("", Number::Fraction(GenericFraction::new_neg(3u32, 2u32)))));
+
+        // a whole number followed by anything but a proper fraction is parsed on its own
+        assert_eq!(parse_number("2 + 3/4"), Ok((" + 3/4", Number::Integer(2))));
+        assert_eq!(parse_number("2 5/4"), Ok((" 5/4", Number::Integer(2))));
+    }
+
+    #[test]
+    fn test_parse_unicode() {
+        assert_eq!(parse_number("½ + 1"), Ok((" + 1", Number::Fraction(GenericFraction::new(1u32, 2u32)))));
+        assert_eq!(parse_number("-⅞"), Ok(("", Number::Fraction(GenericFraction::new_neg(7u32, 8u32)))));
+        assert_eq!(parse_number("+½"), Ok(("", Number::Fraction(GenericFraction::new(1u32, 2u32)))));
+        assert_eq!(parse_number("3⁄4"), Ok(("", Number::Fraction(GenericFraction::new(3u32, 4u32)))));
+        assert_eq!(parse_number("2 3⁄4"), Ok(("", Number::Fraction(GenericFraction::new(11u32, 4u32)))));
+
+        let expected = Number::StandardForm(StandardForm::new(1.2,-3));
+        assert_eq!(parse_number("1.2×10⁻³ m"), Ok((" m", expected.clone())));
+        assert_eq!(parse_number("1.2·10⁻³"), Ok(("", expected.clone())));
+        assert_eq!(parse_number("1.2×10^-3"), Ok(("", expected)));
+        assert_eq!(parse_number("6.02×10²³"), Ok(("", Number::StandardForm(StandardForm::new(6.02,23)))));
+        assert_eq!(parse_number("99×10^126"), Ok(("", Number::StandardForm(StandardForm::new(9.9,127)))));
+    }
+
+    #[test]
+    fn test_parse_unicode_standard_form_out_of_range() {
+        for input in ["inf×10²", "NaN×10²", "1e308×10^100", "99×10^127", "0.01×10^-127", "1e-300×10⁰"] {
+            assert_eq!(
+                parse_unicode_standard_form(input),
+                Err(nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Verify))),
+                "{input}"
+            );
+            assert!(parse_number(input).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_parse_generic_fraction() {
+        let input = "5000000000/3";
+        let expected_result = Ok(("", GenericNumber::Fraction(GenericFraction::new(5_000_000_000u64, 3u64))));
+        assert_eq!(parse_generic_number::<u64>(input), expected_result);
+    }
 }
@@ -4,15 +4,24 @@ use num_traits::{
     cast::FromPrimitive,
     ToPrimitive,
     Num,
-    Pow
+    Pow,
+    CheckedAdd,
+    CheckedSub,
+    CheckedMul,
+    CheckedDiv,
+    CheckedRem
 };
 
+pub use num_traits;
+
 use fraction::GenericFraction;
-use crate::{Number,ParsingNumberError};
+use crate::{GenericNumber,FractionInteger,ParsingNumberError,GenericSymbolic};
+use crate::number::{signed_integer,to_integer,to_u128_fraction};
+use crate::root::exact_root;
 
-impl Zero for Number {
+impl<T : FractionInteger> Zero for GenericNumber<T> {
     fn zero() -> Self {
-        Number::Decimal(0.0)
+        GenericNumber::Decimal(0.0)
     }
 
     fn is_zero(&self) -> bool {
@@ -20,9 +29,9 @@ impl Zero for Number {
     }
 }
 
-impl One for Number {
+impl<T : FractionInteger> One for GenericNumber<T> {
     fn one() -> Self {
-        Number::Decimal(1.0)
+        GenericNumber::Decimal(1.0)
     }
 
     fn is_one(&self) -> bool {
@@ -30,31 +39,84 @@ impl One for Number {
     }
 }
 
-impl FromPrimitive for Number {
+impl<T : FractionInteger> FromPrimitive for GenericNumber<T> {
     // Required methods
     fn from_i64(n: i64) -> Option<Self> {
-        Some(Self::from(n as f64))
+        Some(Self::from(n))
     }
     fn from_u64(n: u64) -> Option<Self> {
-        Some(Self::from(n as f64))
+        Some(Self::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Self::from(n))
+    }
+    fn from_u128(n: u128) -> Option<Self> {
+        match i128::try_from(n) {
+            Ok(n) => Some(Self::from(n)),
+            #[cfg(feature="bigint")]
+            Err(_) => Some(crate::bigint::narrow(fraction::BigFraction::from(n))),
+            #[cfg(not(feature="bigint"))]
+            Err(_) => Some(Self::from(n as f64))
+        }
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        Some(Self::from(n))
+    }
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::from(n))
     }
 }
 
-impl ToPrimitive for Number {
+/// Integers convert exactly, returning `None` if they do not fit. The other variants
+/// are converted through `f64`, truncating towards zero and saturating at the bounds of the type.
+impl<T : FractionInteger> ToPrimitive for GenericNumber<T> {
     // Required methods
     fn to_i64(&self) -> Option<i64> {
+        if let GenericNumber::Integer(i) = self {
+            return i64::try_from(*i).ok();
+        }
+
         let x : f64 = self.clone().into();
 
         Some(x as i64)
     }
     fn to_u64(&self) -> Option<u64> {
+        if let GenericNumber::Integer(i) = self {
+            return u64::try_from(*i).ok();
+        }
+
         let x : f64 = self.clone().into();
 
         Some(x as u64)
     }
+
+    fn to_i128(&self) -> Option<i128> {
+        if let GenericNumber::Integer(i) = self {
+            return Some(*i);
+        }
+
+        let x : f64 = self.clone().into();
+
+        Some(x as i128)
+    }
+    fn to_u128(&self) -> Option<u128> {
+        if let GenericNumber::Integer(i) = self {
+            return u128::try_from(*i).ok();
+        }
+
+        let x : f64 = self.clone().into();
+
+        Some(x as u128)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.clone().into())
+    }
 }
 
-impl Num for Number {
+impl<T : FractionInteger> Num for GenericNumber<T> {
     type FromStrRadixErr = ParsingNumberError;
     #[inline]
     fn from_str_radix(s: &str, _: u32)-> Result<Self,Self::FromStrRadixErr> {
@@ -62,12 +124,19 @@ impl Num for Number {
     }
 }
 
-impl Signed for Number {
+impl<T : FractionInteger> Signed for GenericNumber<T> {
     fn abs(&self) -> Self {
         match self {
-            Number::Decimal(d) => (d.abs()).into(),
-            Number::StandardForm(sf) => (sf.abs()).into(),
-            Number::Fraction(fr) => (fr.abs()).into(),
+            GenericNumber::Integer(i) => match i.checked_abs() {
+                Some(abs) => GenericNumber::Integer(abs),
+                None => -self.clone()
+            },
+            GenericNumber::Decimal(d) => (d.abs()).into(),
+            GenericNumber::StandardForm(sf) => (sf.abs()).into(),
+            GenericNumber::Fraction(fr) => (fr.abs()).into(),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => GenericNumber::BigFraction(fr.abs()),
+            GenericNumber::Symbolic(symbolic) => GenericSymbolic::new(symbolic.coefficient().abs(),symbolic.pi_power(),symbolic.e_power()).into(),
         }
     }
 
@@ -86,31 +155,59 @@ impl Signed for Number {
 
     fn is_positive(&self) -> bool {
         match self {
-            Number::Decimal(d) => d.is_positive(),
-            Number::StandardForm(sf) => sf.is_positive(),
-            Number::Fraction(fr) => fr.is_positive(),
+            GenericNumber::Integer(i) => i.is_positive(),
+            GenericNumber::Decimal(d) => d.is_positive(),
+            GenericNumber::StandardForm(sf) => sf.is_positive(),
+            GenericNumber::Fraction(fr) => fr.is_positive(),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => fr.is_positive(),
+            GenericNumber::Symbolic(symbolic) => symbolic.coefficient().is_positive(),
         }
     }
 
     fn is_negative(&self) -> bool {
         match self {
-            Number::Decimal(d) => d.is_negative(),
-            Number::StandardForm(sf) => sf.is_negative(),
-            Number::Fraction(fr) => fr.is_negative(),
+            GenericNumber::Integer(i) => i.is_negative(),
+            GenericNumber::Decimal(d) => d.is_negative(),
+            GenericNumber::StandardForm(sf) => sf.is_negative(),
+            GenericNumber::Fraction(fr) => fr.is_negative(),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => fr.is_negative(),
+            GenericNumber::Symbolic(symbolic) => symbolic.coefficient().is_negative(),
         }
     }
 }
 
-fn fraction_f64_values<F: FnOnce(f64,f64) -> Number>(fr : GenericFraction<u32>,calculate : F) -> Number {
+macro_rules! checked_operations {
+    ($($trait : ident => $fn : ident),*) => {
+        $(
+            impl<T : FractionInteger> $trait for GenericNumber<T> {
+                fn $fn(&self, v: &Self) -> Option<Self> {
+                    GenericNumber::$fn(self,v).ok()
+                }
+            }
+        )*
+    };
+}
+
+checked_operations!(
+    CheckedAdd => checked_add,
+    CheckedSub => checked_sub,
+    CheckedMul => checked_mul,
+    CheckedDiv => checked_div,
+    CheckedRem => checked_rem
+);
+
+fn fraction_f64_values<T : FractionInteger,F: FnOnce(f64,f64) -> GenericNumber<T>>(fr : GenericFraction<T>,calculate : F) -> GenericNumber<T> {
     match fr {
         GenericFraction::Rational(sign,ratio) => {
-            let mut n = *ratio.numer() as f64;
+            let mut n = ratio.numer().to_f64().unwrap_or(f64::NAN);
 
             if sign.is_negative() {
                 n = -n;
             }
 
-            let d = *ratio.denom() as f64;
+            let d = ratio.denom().to_f64().unwrap_or(f64::NAN);
 
             calculate(n,d)
         },
@@ -118,20 +215,90 @@ fn fraction_f64_values<F: FnOnce(f64,f64) -> Number>(fr : GenericFraction<u32>,c
     }
 }
 
-impl Pow<Self> for Number {
+/// Raises an integer or fraction to a fractional power `p/q` exactly, returning `None` unless its `q`th root is exact.
+fn exact_rational_power<T : FractionInteger>(base : &GenericNumber<T>,exponent : &GenericNumber<T>) -> Option<GenericNumber<T>> {
+    let GenericNumber::Fraction(fr) = exponent else {
+        return None;
+    };
+
+    let GenericFraction::Rational(sign,ratio) = to_u128_fraction(fr)? else {
+        return None;
+    };
+
+    let degree = u32::try_from(*ratio.denom()).ok().filter(|degree| *degree > 1)?;
+    let root = exact_root(base,degree)?;
+    match signed_integer(sign.is_negative(),*ratio.numer())? {
+        1 => Some(root),
+        power => Some(root.pow(GenericNumber::Integer(power)))
+    }
+}
+
+impl<T : FractionInteger> Pow<Self> for GenericNumber<T> {
     type Output = Self;
 
     fn pow(self,other : Self) -> Self {
+        if let Some(power) = exact_rational_power(&self,&other) {
+            return power;
+        }
+
+        // decimals are raised with `powf` like before, everything else stays exact through `powi`
+        if !matches!(self,GenericNumber::Decimal(_)) {
+            if let Some(power) = to_integer(&other).and_then(|power| i32::try_from(power).ok()) {
+                return self.powi(power);
+            }
+        }
+
         match (self,other) {
-            (Number::Decimal(d1), Number::Decimal(d2)) => d1.powf(d2).into(),
-            (Number::Decimal(d), Number::StandardForm(sf)) => d.powf(sf.into()).into(),
-            (Number::Decimal(d1), Number::Fraction(fr)) => fraction_f64_values(fr,|n,d| d1.powf(n/d).into()),
-            (Number::StandardForm(sf), Number::Decimal(d)) => f64::from(sf).powf(d).into(),
-            (Number::StandardForm(sf1), Number::StandardForm(sf2)) => sf1.pow(sf2).into(),
-            (Number::StandardForm(sf), Number::Fraction(fr)) => fraction_f64_values(fr,|n,d| f64::from(sf).powf(n/d).into()),
-            (Number::Fraction(fr), Number::Decimal(d1)) => fraction_f64_values(fr,|n,d| (n/d).powf(d1).into()),
-            (Number::Fraction(fr), Number::StandardForm(sf)) =>fraction_f64_values(fr,|n,d| (n/d).powf(sf.into()).into()),
-            (Number::Fraction(fr1), Number::Fraction(fr2)) => fraction_f64_values(fr1,|n1,d1| fraction_f64_values(fr2,|n2,d2| (n1/d1).powf(n2/d2).into())),
+            (GenericNumber::Decimal(d1), GenericNumber::Decimal(d2)) => d1.powf(d2).into(),
+            (GenericNumber::Decimal(d), GenericNumber::StandardForm(sf)) => d.powf(sf.into()).into(),
+            (GenericNumber::Decimal(d1), GenericNumber::Fraction(fr)) => fraction_f64_values(fr,|n,d| d1.powf(n/d).into()),
+            (GenericNumber::StandardForm(sf), GenericNumber::Decimal(d)) => f64::from(sf).powf(d).into(),
+            (GenericNumber::StandardForm(sf1), GenericNumber::StandardForm(sf2)) => sf1.pow(sf2).into(),
+            (GenericNumber::StandardForm(sf), GenericNumber::Fraction(fr)) => fraction_f64_values(fr,|n,d| f64::from(sf).powf(n/d).into()),
+            (GenericNumber::Fraction(fr), GenericNumber::Decimal(d1)) => fraction_f64_values(fr,|n,d| (n/d).powf(d1).into()),
+            (GenericNumber::Fraction(fr), GenericNumber::StandardForm(sf)) =>fraction_f64_values(fr,|n,d| (n/d).powf(sf.into()).into()),
+            (GenericNumber::Fraction(fr1), GenericNumber::Fraction(fr2)) => fraction_f64_values(fr1,|n1,d1| fraction_f64_values(fr2,|n2,d2| (n1/d1).powf(n2/d2).into())),
+            (lhs,rhs) => f64::from(lhs).powf(rhs.into()).into(),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Number;
+
+    use super::*;
+
+    #[test]
+    fn test_integers_round_trip_exactly() {
+        let big = (1i64 << 60) + 1;
+        assert_eq!(Number::from_i64(big).and_then(|n| n.to_i64()),Some(big));
+        assert_eq!(Number::from_u64(u64::MAX).and_then(|n| n.to_u64()),Some(u64::MAX));
+        assert_eq!(Number::from_i128(i128::MIN).and_then(|n| n.to_i128()),Some(i128::MIN));
+        assert_eq!(Number::from_i64(-1).and_then(|n| n.to_u64()),None);
+    }
+
+    #[test]
+    fn test_floats_stay_decimals() {
+        assert!(matches!(Number::from_f64(2.5),Some(Number::Decimal(d)) if d == 2.5));
+        assert_eq!(Number::Decimal(2.5).to_i64(),Some(2));
+    }
+
+    #[test]
+    fn test_checked_traits() {
+        assert_eq!(CheckedDiv::checked_div(&Number::Integer(1),&Number::Integer(0)),None);
+        assert_eq!(CheckedAdd::checked_add(&Number::Integer(1),&Number::Decimal(0.5)),Some(Number::Decimal(1.5)));
+    }
+
+    #[test]
+    fn test_exact_fractional_powers() {
+        let square_root = Number::Fraction(GenericFraction::new(4u32,9u32)).pow(Number::Fraction(GenericFraction::new(1u32,2u32)));
+        assert!(square_root.structural_eq(&Number::Fraction(GenericFraction::new(2u32,3u32))));
+
+        let cube_root = Number::Integer(-8).pow(Number::Fraction(GenericFraction::new(1u32,3u32)));
+        assert!(cube_root.structural_eq(&Number::Integer(-2)));
+
+        // roots that are not exact are still computed as decimals
+        assert!(Number::Integer(2).pow(Number::Fraction(GenericFraction::new(1u32,2u32))).structural_eq(&Number::Decimal(2_f64.sqrt())));
+    }
+}
@@ -1,17 +1,32 @@
 use std::ops::{Add,Sub,Mul,Div,Rem,AddAssign,SubAssign,MulAssign,DivAssign,RemAssign,Neg};
 use std::cmp::Ordering;
+use std::str::FromStr;
+use std::fmt::Debug;
 
 use standardform::StandardForm;
-use fraction::GenericFraction;
+use fraction::{GenericFraction, Bounded, FromPrimitive, Ratio, Sign, generic::GenericInteger};
 
-use crate::ParsingNumberError;
+use crate::{ParsingNumberError,LossyConversion};
+use crate::PromotionPolicy;
+use crate::interval::Shape;
+use crate::GenericSymbolic;
 
-/// Represents a numeric value that can be either a decimal, a number in standard form,
-/// or a fraction with a generic numerator and denominator.
+/// Represents a numeric value that can be either an integer, a decimal, a number in standard form,
+/// a fraction with a generic numerator and denominator, or a multiple of powers of `π` and `e`.
 ///
 /// This enum is designed for flexible numeric handling in Rust applications.
-#[derive(Debug,Clone,PartialEq,PartialOrd)]
-pub enum Number {
+/// The numerator and denominator of its fractions are of type `T`, see `Number` for the common case of `u32`.
+///
+/// Arithmetic with NaN and infinities follows IEEE 754 regardless of the variants involved,
+/// so `∞ - ∞` and `0 * ∞` are NaN and `x * -∞` takes the sign of the product.
+#[derive(Debug,Clone)]
+pub enum GenericNumber<T : FractionInteger> {
+    /// Represents an exact integer.
+    ///
+    /// Arithmetic between integers stays exact. Division that does not come out even produces a fraction,
+    /// and results that do not fit into an `i128` are decimals, or big fractions with the `bigint` feature.
+    Integer(i128),
+
     /// Represents a floating-point decimal number.
     Decimal(f64),
 
@@ -19,78 +34,391 @@ pub enum Number {
     StandardForm(StandardForm),
 
     /// Represents a fraction with a generic numerator and denominator
-    Fraction(GenericFraction<u32>)
+    Fraction(GenericFraction<T>),
+
+    /// Represents a fraction whose numerator and denominator can be arbitrarily large.
+    ///
+    /// Arithmetic between fractions produces this variant whenever the result does not fit into `Number::Fraction`.
+    #[cfg(feature="bigint")]
+    BigFraction(fraction::BigFraction),
+
+    /// Represents a multiple of powers of the constants `π` and `e`, such as `2π/3`.
+    ///
+    /// Numbers with constants are only produced from an exact, non-zero coefficient and at least one
+    /// non-zero power, otherwise they are held as their coefficient or as a decimal. Multiplying and dividing
+    /// them by integers, fractions and each other keeps the constants symbolic, as does adding or subtracting
+    /// numbers with the same powers, unless the policy prefers decimals or standard form. Every other operation,
+    /// and comparisons, evaluate the constants into a decimal.
+    Symbolic(Box<GenericSymbolic<T>>)
 }
 
-impl std::fmt::Display for Number {
+/// A number whose fractions have a `u32` numerator and denominator.
+pub type Number = GenericNumber<u32>;
+
+/// An integer type that can hold the numerator and denominator of the fractions in a `GenericNumber`.
+///
+/// This is implemented for all primitive integers. Fractions whose numerator or denominator
+/// do not fit into `u128` are converted into other notations approximately.
+pub trait FractionInteger : GenericInteger + Bounded + Clone + Debug + FromPrimitive + FromStr {}
+
+impl<T : GenericInteger + Bounded + Clone + Debug + FromPrimitive + FromStr> FractionInteger for T {}
+
+impl<T : FractionInteger> std::fmt::Display for GenericNumber<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Number::Decimal(d) => write!(f,"{d}"),
-            Number::StandardForm(sf) => write!(f,"{sf}"),
-            Number::Fraction(fr) => write!(f,"{fr}"),
+            GenericNumber::Integer(i) => write!(f,"{i}"),
+            GenericNumber::Decimal(d) => write!(f,"{d}"),
+            GenericNumber::StandardForm(sf) => write!(f,"{sf}"),
+            GenericNumber::Fraction(fr) => write!(f,"{fr}"),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => write!(f,"{fr}"),
+            GenericNumber::Symbolic(symbolic) => write!(f,"{symbolic}"),
         }
     }
 }
 
-impl From<StandardForm> for Number {
+impl<T : FractionInteger> From<StandardForm> for GenericNumber<T> {
     fn from(value: StandardForm) -> Self {
-        Number::StandardForm(value)
+        GenericNumber::StandardForm(value)
     }
 }
 
-impl From<GenericFraction<u32>> for Number {
-    fn from(value: GenericFraction<u32>) -> Self {
-        Number::Fraction(value)
+impl<T : FractionInteger> From<GenericFraction<T>> for GenericNumber<T> {
+    fn from(value: GenericFraction<T>) -> Self {
+        GenericNumber::Fraction(value)
     }
 }
 
-impl From<f64> for Number {
+impl<T : FractionInteger> From<f64> for GenericNumber<T> {
     fn from(value: f64) -> Self {
-        Number::Decimal(value)
+        GenericNumber::Decimal(value)
     }
 }
 
-impl From<Number> for f64 {
-    fn from(value: Number) -> Self {
+impl<T : FractionInteger> From<f32> for GenericNumber<T> {
+    fn from(value: f32) -> Self {
+        GenericNumber::Decimal(value.into())
+    }
+}
+
+macro_rules! from_integers {
+    ($($t : ty),*) => {
+        $(
+            impl<T : FractionInteger> From<$t> for GenericNumber<T> {
+                fn from(value: $t) -> Self {
+                    GenericNumber::Integer(value.into())
+                }
+            }
+        )*
+    };
+}
+
+from_integers!(i8,i16,i32,i64,i128,u8,u16,u32,u64);
+
+impl<T : FractionInteger> From<GenericNumber<T>> for f64 {
+    fn from(value: GenericNumber<T>) -> Self {
         match value {
-            Number::Decimal(d) => d,
-            Number::StandardForm(sf) => sf.into(),
-            Number::Fraction(fr) => match fr {
-                GenericFraction::Rational(sign,ratio) => match sign.is_positive() {
-                    true => ratio.to_integer() as f64 ,
-                    false => -(ratio.to_integer() as f64)
-                },
-                GenericFraction::Infinity(sign) => match sign.is_positive() {
-                    true => f64::INFINITY,
-                    false => -f64::INFINITY
-                },
-                GenericFraction::NaN => f64::NAN,
-            },
+            GenericNumber::Integer(i) => i as f64,
+            GenericNumber::Decimal(d) => d,
+            GenericNumber::StandardForm(sf) => sf.into(),
+            GenericNumber::Fraction(fr) => fraction_to_f64(&fr),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => crate::bigint::big_fraction_to_f64(&fr),
+            GenericNumber::Symbolic(symbolic) => symbolic.to_f64(),
+        }
+    }
+}
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Converts the number into an `f64`, failing if its value cannot be represented exactly.
+    ///
+    /// A number in standard form is read as the decimal written by its mantissa multiplied by
+    /// ten to the power of its exponent, so `1.2*10^3` converts exactly while `1.2*10^-3` does not.
+    /// The error carries the nearest `f64` to the value.
+    pub fn to_f64_exact(&self) -> Result<f64,LossyConversion> {
+        match self {
+            GenericNumber::Integer(i) => self.exactly(*i as f64),
+            GenericNumber::Decimal(d) => Ok(*d),
+            GenericNumber::StandardForm(sf) => standard_form_to_f64_exact(sf),
+            GenericNumber::Fraction(fr) => self.exactly(fraction_to_f64(fr)),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => self.exactly(crate::bigint::big_fraction_to_f64(fr)),
+            // the constants are irrational, so no power of them other than the zeroth is a float
+            GenericNumber::Symbolic(symbolic) => Err(LossyConversion::new(symbolic.to_f64())),
+        }
+    }
+
+    /// Accepts `float` only if its exact binary value is the value of the number, as decimals compare by it.
+    fn exactly(&self,float : f64) -> Result<f64,LossyConversion> {
+        match *self == GenericNumber::Decimal(float) {
+            true => Ok(float),
+            false => Err(LossyConversion::new(float))
+        }
+    }
+}
+
+/// Converts a fraction into the nearest `f64`, rounding ties to even.
+pub(crate) fn fraction_to_f64<T : FractionInteger>(fr : &GenericFraction<T>) -> f64 {
+    let Some(fr) = to_u128_fraction(fr) else {
+        return wide_fraction_to_f64(fr);
+    };
+
+    match fr {
+        GenericFraction::Rational(sign,ratio) => {
+            let float = ratio_to_f64(*ratio.numer(),*ratio.denom());
+            match sign.is_positive() {
+                true => float,
+                false => -float
+            }
+        },
+        GenericFraction::Infinity(sign) => match sign.is_positive() {
+            true => f64::INFINITY,
+            false => -f64::INFINITY
+        },
+        GenericFraction::NaN => f64::NAN,
+    }
+}
+
+#[cfg(feature="bigint")]
+fn wide_fraction_to_f64<T : FractionInteger>(fr : &GenericFraction<T>) -> f64 {
+    crate::bigint::big_fraction_to_f64(&crate::bigint::widen(fr))
+}
+
+#[cfg(not(feature="bigint"))]
+fn wide_fraction_to_f64<T : FractionInteger>(fr : &GenericFraction<T>) -> f64 {
+    fraction::ToPrimitive::to_f64(fr).unwrap_or(f64::NAN)
+}
+
+/// Converts a fraction into one over `u128` with the same value, returning `None` if its numerator or denominator does not fit.
+pub(crate) fn to_u128_fraction<T : FractionInteger>(fr : &GenericFraction<T>) -> Option<GenericFraction<u128>> {
+    // signed integers may carry the sign of the fraction in its numerator or denominator
+    let magnitude = |n : &T| match *n < T::zero() {
+        true => n.to_i128().map(|n| (Sign::Minus,n.unsigned_abs())),
+        false => n.to_u128().map(|n| (Sign::Plus,n))
+    };
+
+    Some(match fr {
+        GenericFraction::Rational(sign,ratio) => {
+            let (numer_sign,numer) = magnitude(ratio.numer())?;
+            let (denom_sign,denom) = magnitude(ratio.denom())?;
+            GenericFraction::Rational(*sign * numer_sign * denom_sign,Ratio::new_raw(numer,denom))
+        },
+        GenericFraction::Infinity(sign) => GenericFraction::Infinity(*sign),
+        GenericFraction::NaN => GenericFraction::NaN,
+    })
+}
+
+/// Divides two integers, rounding the quotient to the nearest `f64` with ties to even.
+pub(crate) fn ratio_to_f64(numer : u128,denom : u128) -> f64 {
+    const EXACT : u128 = 1 << f64::MANTISSA_DIGITS;
+
+    if numer < EXACT && denom < EXACT {
+        return numer as f64 / denom as f64;
+    }
+
+    let (mut quotient,mut remainder) = (numer / denom,numer % denom);
+    let mut shift = 0;
+
+    // generate bits until there are enough below the mantissa to round correctly
+    while quotient < EXACT << 2 {
+        let bit = remainder >= denom - remainder;
+        remainder = match bit {
+            true => remainder - (denom - remainder),
+            false => remainder << 1
+        };
+        quotient = quotient << 1 | bit as u128;
+        shift += 1;
+    }
+
+    // any remainder only breaks ties, so it can be folded into the lowest bit
+    let quotient = quotient | (remainder != 0) as u128;
+
+    quotient as f64 * 2_f64.powi(-shift)
+}
+
+fn standard_form_to_f64_exact(sf : &StandardForm) -> Result<f64,LossyConversion> {
+    let (mantissa,exponent) = (*sf.mantissa(),*sf.exponent() as i32);
+    let nearest : f64 = match mantissa.is_finite() {
+        true => format!("{mantissa}e{exponent}").parse().expect("finite floats format as valid floats"),
+        false => mantissa
+    };
+
+    if !mantissa.is_finite() || mantissa == 0.0 {
+        return Ok(nearest);
+    }
+
+    let (significand,power) = sf_decimal(sf);
+
+    let odd_part = |n : u128| n >> n.trailing_zeros();
+    let fits = |n : u128| n < 1 << f64::MANTISSA_DIGITS;
+
+    let exact = match power >= 0 {
+        true => 5_u128.checked_pow(power as u32).and_then(|five| odd_part(significand).checked_mul(five)).is_some_and(fits),
+        false => match 5_u128.checked_pow(power.unsigned_abs()) {
+            Some(five) => significand.is_multiple_of(five) && fits(odd_part(significand / five)),
+            None => false
         }
+    };
+
+    match exact {
+        true => Ok(nearest),
+        false => Err(LossyConversion::new(nearest))
+    }
+}
+
+/// Splits a finite, non-zero number in standard form into the decimal it is read as, `±significand * 10^power`.
+pub(crate) fn sf_decimal(sf : &StandardForm) -> (u128,i32) {
+    // the mantissa is the shortest decimal that round trips, so its digits are the intended value
+    let digits = sf.mantissa().abs().to_string();
+    let (whole,decimals) = digits.split_once('.').unwrap_or((&digits,""));
+    let significand : u128 = format!("{whole}{decimals}").parse().expect("digits of a finite float");
+    (significand,*sf.exponent() as i32 - decimals.len() as i32)
+}
+
+/// Converts a number into a fraction, returning `None` if the fraction cannot hold its value exactly.
+///
+/// Decimals and numbers in standard form are read as the decimals they are written as, so `0.1` becomes `1/10`.
+#[cfg(not(feature="bigint"))]
+pub(crate) fn to_fraction<T : FractionInteger>(number : &GenericNumber<T>) -> Option<GenericFraction<T>> {
+    match number {
+        GenericNumber::Integer(i) => {
+            let numer = T::from_u128(i.unsigned_abs())?;
+            Some(match i.is_negative() {
+                true => GenericFraction::new_neg(numer,T::one()),
+                false => GenericFraction::new(numer,T::one())
+            })
+        },
+        GenericNumber::Fraction(fr) => Some(fr.clone()),
+        GenericNumber::Decimal(d) => {
+            let fraction = GenericFraction::from(*d);
+            (d.is_nan() || fraction_to_f64(&fraction) == *d).then_some(fraction)
+        },
+        GenericNumber::StandardForm(sf) if !sf.mantissa().is_finite() || *sf.mantissa() == 0.0 => Some(GenericFraction::from(*sf.mantissa())),
+        GenericNumber::StandardForm(sf) => {
+            let (significand,power) = sf_decimal(sf);
+            let scale = 10_u128.checked_pow(power.unsigned_abs())?;
+            let ratio = match power >= 0 {
+                true => fraction::Ratio::from_integer(significand.checked_mul(scale)?),
+                false => fraction::Ratio::new(significand,scale)
+            };
+
+            let (numer,denom) = (T::from_u128(*ratio.numer())?,T::from_u128(*ratio.denom())?);
+            Some(match sf.mantissa().is_sign_negative() {
+                true => GenericFraction::new_neg(numer,denom),
+                false => GenericFraction::new(numer,denom)
+            })
+        },
+        GenericNumber::Symbolic(_) => None,
+    }
+}
+
+/// Converts a number into an integer, returning `None` if its value is not an integer that fits into an `i128`.
+///
+/// Unlike `to_fraction`, decimals are read as their exact binary value, which is the same for integers below 2^53.
+pub(crate) fn to_integer<T : FractionInteger>(number : &GenericNumber<T>) -> Option<i128> {
+    match number {
+        GenericNumber::Integer(i) => Some(*i),
+        GenericNumber::Decimal(d) => float_to_integer(*d),
+        GenericNumber::StandardForm(sf) if *sf.mantissa() == 0.0 => Some(0),
+        GenericNumber::StandardForm(sf) if !sf.mantissa().is_finite() => None,
+        GenericNumber::StandardForm(sf) => {
+            let (significand,power) = sf_decimal(sf);
+            let scale = 10_u128.checked_pow(power.unsigned_abs())?;
+            let magnitude = match power >= 0 {
+                true => significand.checked_mul(scale)?,
+                false => significand.is_multiple_of(scale).then_some(significand / scale)?
+            };
+            signed_integer(sf.mantissa().is_sign_negative(),magnitude)
+        },
+        GenericNumber::Fraction(fr) => match to_u128_fraction(fr)? {
+            GenericFraction::Rational(sign,ratio) if ratio.is_integer() => signed_integer(sign.is_negative(),ratio.to_integer()),
+            _ => None
+        },
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(fr) => match fr {
+            GenericFraction::Rational(sign,ratio) if ratio.is_integer() => signed_integer(sign.is_negative(),fraction::ToPrimitive::to_u128(&ratio.to_integer())?),
+            _ => None
+        },
+        GenericNumber::Symbolic(_) => None,
+    }
+}
+
+/// Returns `true` if the number is an integer or a fraction, which arithmetic does not round.
+pub(crate) fn is_exact<T : FractionInteger>(number : &GenericNumber<T>) -> bool {
+    match number {
+        GenericNumber::Integer(_) => true,
+        GenericNumber::Fraction(fr) => fr.is_finite() && !fr.is_nan(),
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(fr) => fr.is_finite() && !fr.is_nan(),
+        _ => false
+    }
+}
+
+/// Converts a float into an integer, returning `None` if it is not an integer that fits into an `i128`.
+pub(crate) fn float_to_integer(float : f64) -> Option<i128> {
+    // floats with a magnitude of at least 2^127 do not fit, apart from `i128::MIN` itself
+    (float.fract() == 0.0 && (-(2_f64.powi(127))..2_f64.powi(127)).contains(&float)).then_some(float as i128)
+}
+
+pub(crate) fn signed_integer(negative : bool,magnitude : u128) -> Option<i128> {
+    match negative {
+        true => 0_i128.checked_sub_unsigned(magnitude),
+        false => i128::try_from(magnitude).ok()
     }
 }
-impl TryFrom<&str> for Number {
+
+impl<T : FractionInteger> TryFrom<&str> for GenericNumber<T> {
     type Error = ParsingNumberError;
+
+    /// Parses a number, trying each notation in turn.
+    ///
+    /// Whole numbers that fit into an `i128`, such as `5`, become `Number::Integer` so they stay exact beyond 2^53,
+    /// while `5.0` and `5e0` are still decimals. Fractions, standard form, repeating decimals, mixed numbers,
+    /// Unicode notation and multiples of `π` and `e` such as `2pi/3` are tried after decimals.
     fn try_from(value : &str) -> Result<Self, Self::Error> {
 
+        if let Ok(integer) = value.parse::<i128>() {
+            return Ok(GenericNumber::Integer(integer));
+        }
+
         let try_into_f64 = value.parse::<f64>();
 
         if let Ok(double) = try_into_f64 {
-            return Ok(Number::Decimal(double));
+            return Ok(GenericNumber::Decimal(double));
         } 
 
-        let try_into_fraction = value.parse::<GenericFraction<u32>>();
+        let try_into_fraction = value.parse::<GenericFraction<T>>();
 
         if let Ok(fraction) = try_into_fraction {
-            return Ok(Number::Fraction(fraction));
+            return Ok(GenericNumber::Fraction(fraction));
         } 
+
+        #[cfg(feature="bigint")]
+        if let Ok(fraction) = value.parse::<fraction::BigFraction>() {
+            return Ok(crate::bigint::narrow(fraction));
+        }
     
-        let try_into_sf = StandardForm::try_from(value);
+        // `StandardForm` slices three bytes before a `^` without checking, which panics on `2^3` or `1.2×10^3`
+        let try_into_sf = match value.find('^') {
+            Some(index) if !value.contains('e') && !index.checked_sub(3).is_some_and(|start| value.is_char_boundary(start)) => {
+                Err(standardform::ParsingStandardFormError::InvalidFormat)
+            },
+            _ => StandardForm::try_from(value)
+        };
 
         if let Ok(sf) = try_into_sf {
-            return Ok(Number::StandardForm(sf));
+            return Ok(GenericNumber::StandardForm(sf));
         } 
 
+        let special_notation = crate::repeating::parse_repeating(value)
+            .or_else(|| crate::mixed::parse_mixed(value))
+            .or_else(|| crate::unicode::parse_unicode(value))
+            .or_else(|| crate::symbolic::parse_symbolic(value));
+
+        if let Some(number) = special_notation {
+            return Ok(number);
+        }
+
         Err(ParsingNumberError::new(
             try_into_fraction.unwrap_err(),
             try_into_f64.unwrap_err(),
@@ -99,196 +427,193 @@ impl TryFrom<&str> for Number {
     }
 }
 
-impl Neg for Number {
+impl<T : FractionInteger> Neg for GenericNumber<T> {
     type Output = Self;
     fn neg(self) -> Self::Output {
         match self {
-            Number::Decimal(d) => Number::Decimal(-d),
-            Number::StandardForm(sf) => Number::StandardForm(-sf),
-            Number::Fraction(fr) => Number::Fraction(-fr),
+            GenericNumber::Integer(i) => match i.checked_neg() {
+                Some(negated) => GenericNumber::Integer(negated),
+                // `-i128::MIN` overflows, so it is promoted like any other integer arithmetic
+                None => GenericNumber::Integer(0) - GenericNumber::Integer(i)
+            },
+            GenericNumber::Decimal(d) => GenericNumber::Decimal(-d),
+            GenericNumber::StandardForm(sf) => GenericNumber::StandardForm(-sf),
+            GenericNumber::Fraction(fr) => GenericNumber::Fraction(-fr),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => GenericNumber::BigFraction(-fr),
+            GenericNumber::Symbolic(symbolic) => GenericNumber::from(-*symbolic),
         }
     }
 }
 
-impl Eq for Number {}
 
-impl Ord for Number {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+/// Converts a finite fraction into standard form.
+///
+/// Non-terminating decimals such as `1/3` are rounded to the nearest mantissa an `f64` can hold.
+pub(crate) fn fraction_to_sf<T : FractionInteger>(fr : &GenericFraction<T>) -> StandardForm {
+    match to_u128_fraction(fr) {
+        Some(GenericFraction::Rational(sign,ratio)) => {
+            let sf = ratio_to_sf(*ratio.numer(),*ratio.denom());
+            match sign {
+                Sign::Plus => sf,
+                Sign::Minus => -sf
+            }
+        },
+        Some(_) => unreachable!("standard form cannot hold NaN or infinities"),
+        None => wide_fraction_to_sf(fr)
     }
 }
 
+#[cfg(feature="bigint")]
+fn wide_fraction_to_sf<T : FractionInteger>(fr : &GenericFraction<T>) -> StandardForm {
+    crate::bigint::big_fraction_to_sf(&crate::bigint::widen(fr))
+}
 
-fn from_fraction_rational_to_sf(sign : fraction::Sign,ratio : fraction::Ratio<u32>) -> StandardForm {
-    let (mantissa,exponent) = (match sign{
-        fraction::Sign::Plus => *ratio.numer() as f64,
-        fraction::Sign::Minus => -(*ratio.numer() as f64)
-    }, -(ratio.denom().trailing_zeros() as i8));
-    StandardForm::new(mantissa,exponent)
+#[cfg(not(feature="bigint"))]
+fn wide_fraction_to_sf<T : FractionInteger>(fr : &GenericFraction<T>) -> StandardForm {
+    fraction_to_f64(fr).into()
 }
 
+/// Divides two integers into standard form, rounding the mantissa to the nearest `f64`.
+pub(crate) fn ratio_to_sf(numer : u128,denom : u128) -> StandardForm {
+    // more digits than an `f64` can distinguish, so parsing them rounds the mantissa correctly
+    const SIGNIFICANT_DIGITS : usize = 21;
 
-impl Add for Number {
-    type Output = Number;
+    if numer == 0 {
+        return StandardForm::new(0.0,0);
+    }
 
-    fn add(self,other : Number) -> Self::Output {
-        use crate::Number::*;
-        match (self,other) {
-            (Decimal(d1),Decimal(d2)) => (d1 + d2).into(),
-            (Decimal(d),StandardForm(sf)) => (sf + d).into(),
-            (Decimal(d),Fraction(fr)) => (fr + d).into(),
+    let (whole,mut remainder) = (numer / denom,numer % denom);
+    let mut digits = match whole {
+        0 => String::new(),
+        whole => whole.to_string()
+    };
+    let mut exponent = digits.len() as i32 - 1;
 
-            (StandardForm(sf1),StandardForm(sf2)) => (sf1 + sf2).into(),
-            (StandardForm(sf),Decimal(d)) => (sf + d).into(),
-            (StandardForm(sf),Fraction(fr)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (sf + from_fraction_rational_to_sf(sign,ratio)).into(),
-                _ => fr.into()
-            },
+    while digits.len() < SIGNIFICANT_DIGITS && remainder != 0 {
+        let (digit,next) = times_ten_div_rem(remainder,denom);
+        remainder = next;
 
-            (Fraction(fr1),Fraction(fr2)) => (fr1 + fr2).into(),
-            (Fraction(fr),Decimal(d)) => (fr + d).into(),
-            (Fraction(fr),StandardForm(sf)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (from_fraction_rational_to_sf(sign,ratio) + sf).into(),
-                _ => fr.into()
-            },
+        match digits.is_empty() && digit == 0 {
+            true => exponent -= 1,
+            false => digits.push(char::from(b'0' + digit))
         }
     }
-}
 
-impl Sub for Number {
-    type Output = Number;
+    // digits left over only break ties, so a trailing one stands in for them
+    if remainder != 0 {
+        digits.push('1');
+    }
 
-    fn sub(self,other : Number) -> Self::Output {
-        use crate::Number::*;
-        match (self,other) {
-            (Decimal(d1),Decimal(d2)) => (d1 - d2).into(),
-            (Decimal(d),StandardForm(sf)) => (sf - d).into(),
-            (Decimal(d),Fraction(fr)) => (fr - d).into(),
+    digits_to_sf(&digits,exponent)
+}
 
-            (StandardForm(sf1),StandardForm(sf2)) => (sf1 - sf2).into(),
-            (StandardForm(sf),Decimal(d)) => (sf - d).into(),
-            (StandardForm(sf),Fraction(fr)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (sf - from_fraction_rational_to_sf(sign,ratio)).into(),
-                _ => fr.into()
-            },
+/// Converts a finite float in the range of standard form into standard form, keeping the digits it is written as.
+pub(crate) fn float_to_sf(float : f64) -> StandardForm {
+    // unlike repeatedly dividing by ten, the shortest scientific notation does not round the mantissa
+    let scientific = format!("{float:e}");
+    let (mantissa,exponent) = scientific.split_once('e').expect("floats format with an exponent");
+    StandardForm::new(mantissa.parse().expect("a valid mantissa"),exponent.parse().expect("an exponent in range"))
+}
 
-            (Fraction(fr1),Fraction(fr2)) => (fr1 - fr2).into(),
-            (Fraction(fr),Decimal(d)) => (fr - d).into(),
-            (Fraction(fr),StandardForm(sf)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (from_fraction_rational_to_sf(sign,ratio) - sf).into(),
-                _ => fr.into()
-            },
-        }
+/// Converts the significant digits of a number and the exponent of its first digit into standard form.
+pub(crate) fn digits_to_sf(digits : &str,exponent : i32) -> StandardForm {
+    let mantissa : f64 = format!("{}.{}",&digits[..1],&digits[1..]).parse().expect("decimal digits form a valid float");
+
+    // rounding can carry a mantissa of 9.999... up to 10
+    match mantissa >= 10.0 {
+        true => StandardForm::new(mantissa / 10.0,(exponent + 1) as i8),
+        false => StandardForm::new(mantissa,exponent as i8)
     }
 }
 
-impl Mul for Number {
-    type Output = Number;
+/// Computes `remainder * 10` divided by `denom` without overflowing, given `remainder < denom`.
+pub(crate) fn times_ten_div_rem(remainder : u128,denom : u128) -> (u8,u128) {
+    // adds two values below `denom`, returning the carry and the sum modulo `denom`
+    let add = |a : u128,b : u128| match a >= denom - b {
+        true => (1,a - (denom - b)),
+        false => (0,a + b)
+    };
 
-    fn mul(self,other : Number) -> Self::Output {
-        use crate::Number::*;
-        match (self,other) {
-            (Decimal(d1),Decimal(d2)) => (d1 * d2).into(),
-            (Decimal(d),StandardForm(sf)) => (sf * d).into(),
-            (Decimal(d),Fraction(fr)) => (fr * d).into(),
+    let (carry2,twice) = add(remainder,remainder);
+    let (carry4,four_times) = add(twice,twice);
+    let (carry8,eight_times) = add(four_times,four_times);
+    let (carry10,ten_times) = add(eight_times,twice);
 
-            (StandardForm(sf1),StandardForm(sf2)) => (sf1 * sf2).into(),
-            (StandardForm(sf),Decimal(d)) => (sf * d).into(),
-            (StandardForm(sf),Fraction(fr)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (sf * from_fraction_rational_to_sf(sign,ratio)).into(),
-                _ => fr.into()
-            },
+    (carry10 + carry8 + 2 * carry4 + 5 * carry2,ten_times)
+}
 
-            (Fraction(fr1),Fraction(fr2)) => (fr1 * fr2).into(),
-            (Fraction(fr),Decimal(d)) => (fr * d).into(),
-            (Fraction(fr),StandardForm(sf)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (from_fraction_rational_to_sf(sign,ratio) * sf).into(),
-                _ => fr.into()
-            },
-        }
+/// Computes the remainder of two numbers in standard form, truncating the quotient towards zero like `f64`.
+pub(crate) fn sf_rem(lhs : StandardForm,rhs : StandardForm) -> StandardForm {
+    (f64::from(lhs) % f64::from(rhs)).into()
+}
+
+impl<T : FractionInteger> Add for GenericNumber<T> {
+    type Output = GenericNumber<T>;
+
+    fn add(self,other : GenericNumber<T>) -> Self::Output {
+        self.add_with(&other,PromotionPolicy::default())
     }
 }
 
-impl Div for Number {
-    type Output = Number;
+impl<T : FractionInteger> Sub for GenericNumber<T> {
+    type Output = GenericNumber<T>;
 
-    fn div(self,other : Number) -> Self::Output {
-        use crate::Number::*;
-        match (self,other) {
-            (Decimal(d1),Decimal(d2)) => (d1 / d2).into(),
-            (Decimal(d),StandardForm(sf)) => (sf / d).into(),
-            (Decimal(d),Fraction(fr)) => (fr / d).into(),
+    fn sub(self,other : GenericNumber<T>) -> Self::Output {
+        self.sub_with(&other,PromotionPolicy::default())
+    }
+}
 
-            (StandardForm(sf1),StandardForm(sf2)) => (sf1 / sf2).into(),
-            (StandardForm(sf),Decimal(d)) => (sf / d).into(),
-            (StandardForm(sf),Fraction(fr)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (sf / from_fraction_rational_to_sf(sign,ratio)).into(),
-                _ => fr.into()
-            },
+impl<T : FractionInteger> Mul for GenericNumber<T> {
+    type Output = GenericNumber<T>;
 
-            (Fraction(fr1),Fraction(fr2)) => (fr1 / fr2).into(),
-            (Fraction(fr),Decimal(d)) => (fr / d).into(),
-            (Fraction(fr),StandardForm(sf)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (from_fraction_rational_to_sf(sign,ratio) / sf).into(),
-                _ => fr.into()
-            },
-        }
+    fn mul(self,other : GenericNumber<T>) -> Self::Output {
+        self.mul_with(&other,PromotionPolicy::default())
     }
 }
 
-impl Rem for Number {
-    type Output = Number;
+impl<T : FractionInteger> Div for GenericNumber<T> {
+    type Output = GenericNumber<T>;
 
-    fn rem(self,other : Number) -> Self::Output {
-        use crate::Number::*;
-        match (self,other) {
-            (Decimal(d1),Decimal(d2)) => (d1 % d2).into(),
-            (Decimal(d),StandardForm(sf)) => (sf % d).into(),
-            (Decimal(d),Fraction(fr)) => (fr % d).into(),
+    fn div(self,other : GenericNumber<T>) -> Self::Output {
+        self.div_with(&other,PromotionPolicy::default())
+    }
+}
 
-            (StandardForm(sf1),StandardForm(sf2)) => (sf1 % sf2).into(),
-            (StandardForm(sf),Decimal(d)) => (sf % d).into(),
-            (StandardForm(sf),Fraction(fr)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (sf % from_fraction_rational_to_sf(sign,ratio)).into(),
-                _ => fr.into()
-            },
+impl<T : FractionInteger> Rem for GenericNumber<T> {
+    type Output = GenericNumber<T>;
 
-            (Fraction(fr1),Fraction(fr2)) => (fr1 % fr2).into(),
-            (Fraction(fr),Decimal(d)) => (fr % d).into(),
-            (Fraction(fr),StandardForm(sf)) => match fr {
-                GenericFraction::Rational(sign,ratio) => (from_fraction_rational_to_sf(sign,ratio) % sf).into(),
-                _ => fr.into()
-            },
-        }
+    fn rem(self,other : GenericNumber<T>) -> Self::Output {
+        self.rem_with(&other,PromotionPolicy::default())
     }
 }
 
-impl AddAssign for Number {
-    fn add_assign(&mut self, other: Number) {
+impl<T : FractionInteger> AddAssign for GenericNumber<T> {
+    fn add_assign(&mut self, other: GenericNumber<T>) {
         *self = self.clone() + other;
     }
 }
 
-impl SubAssign for Number {
-    fn sub_assign(&mut self, other: Number) {
+impl<T : FractionInteger> SubAssign for GenericNumber<T> {
+    fn sub_assign(&mut self, other: GenericNumber<T>) {
         *self = self.clone() - other;
     }
 }
 
-impl MulAssign for Number {
-    fn mul_assign(&mut self, other: Number) {
+impl<T : FractionInteger> MulAssign for GenericNumber<T> {
+    fn mul_assign(&mut self, other: GenericNumber<T>) {
         *self = self.clone() * other;
     }
 }
 
-impl DivAssign for Number {
-    fn div_assign(&mut self, other: Number) {
+impl<T : FractionInteger> DivAssign for GenericNumber<T> {
+    fn div_assign(&mut self, other: GenericNumber<T>) {
         *self = self.clone() / other;
     }
 }
 
-impl RemAssign for Number {
-    fn rem_assign(&mut self, other: Number) {
+impl<T : FractionInteger> RemAssign for GenericNumber<T> {
+    fn rem_assign(&mut self, other: GenericNumber<T>) {
         *self = self.clone() % other;
     }
 }
@@ -297,38 +622,18 @@ impl RemAssign for Number {
 macro_rules! primitives {
     (eq => $($t : ty),*) => {
         $(
-            impl PartialEq<$t> for Number {
+            impl<T : FractionInteger> PartialEq<$t> for GenericNumber<T> {
                 fn eq(&self, other: &$t) -> bool {
-                    match self {
-                        Number::Decimal(d) => d == &(*other as f64),
-                        Number::StandardForm(sf) => {
-                            let xy : StandardForm = (*other).into();
-                            sf == &xy
-                        },
-                        Number::Fraction(fr) => {
-                            let xy : GenericFraction<u32> = (*other).into();
-                            fr == &xy
-                        }
-                    }
+                    self.cmp(&GenericNumber::from(*other)) == Ordering::Equal
                 }
             }
         )*
     };
     (ord => $($t : ty),*) => {
         $(
-            impl PartialOrd<$t> for Number {
+            impl<T : FractionInteger> PartialOrd<$t> for GenericNumber<T> {
                 fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
-                    match self {
-                        Number::Decimal(f) => f.partial_cmp(&(*other as f64)),
-                        Number::StandardForm(sf) => {
-                            let xy : StandardForm = (*other).into();
-                            sf.partial_cmp(&xy)
-                        },
-                        Number::Fraction(fr) => {
-                            let xy : GenericFraction<u32> = (*other).into();
-                            fr.partial_cmp(&xy)
-                        }
-                    }
+                    self.partial_cmp(&GenericNumber::from(*other))
                 }
             }
         )*
@@ -336,20 +641,24 @@ macro_rules! primitives {
 
     (add => $($t : ty),*) => {
         $(
-            impl Add<$t> for Number {
+            impl<T : FractionInteger> Add<$t> for GenericNumber<T> {
                 type Output = Self;
                 fn add(self, other: $t) -> Self {
                     match self {
-                        Number::Decimal(f) => (f + other as f64).into(),
-                        Number::StandardForm(sf) =>(sf + other).into(),
-                        Number::Fraction(fr) => (fr + other).into()
+                        GenericNumber::Decimal(f) => (f + other as f64).into(),
+                        GenericNumber::Integer(_) => self + GenericNumber::from(other),
+                        GenericNumber::StandardForm(_) => self + GenericNumber::Decimal(other as f64),
+                        GenericNumber::Fraction(_) => self + GenericNumber::Fraction(other.into()),
+                        #[cfg(feature="bigint")]
+                        GenericNumber::BigFraction(_) => self + GenericNumber::BigFraction(other.into()),
+                        GenericNumber::Symbolic(_) => self + GenericNumber::from(other),
                     }
                 }
             }
             
-            impl AddAssign<$t> for Number {
+            impl<T : FractionInteger> AddAssign<$t> for GenericNumber<T> {
                 fn add_assign(&mut self, other: $t) {
-                    *self += Number::Decimal(other as f64)
+                    *self += GenericNumber::from(other)
                 }
             }
         )*
@@ -357,81 +666,96 @@ macro_rules! primitives {
 
     (sub => $($t : ty),*) => {
         $(
-            impl Sub<$t> for Number {
+            impl<T : FractionInteger> Sub<$t> for GenericNumber<T> {
                 type Output = Self;
                 fn sub(self, other: $t) -> Self {
                     match self {
-                        Number::Decimal(f) => Number::Decimal(f - other as f64),
-                        Number::StandardForm(sf) => Number::StandardForm(sf - other),
-                        Number::Fraction(fr) => (fr - other).into()
-
+                        GenericNumber::Decimal(f) => GenericNumber::Decimal(f - other as f64),
+                        GenericNumber::Integer(_) => self - GenericNumber::from(other),
+                        GenericNumber::StandardForm(_) => self - GenericNumber::Decimal(other as f64),
+                        GenericNumber::Fraction(_) => self - GenericNumber::Fraction(other.into()),
+                        #[cfg(feature="bigint")]
+                        GenericNumber::BigFraction(_) => self - GenericNumber::BigFraction(other.into()),
+                        GenericNumber::Symbolic(_) => self - GenericNumber::from(other),
                     }
                 }
             }
             
-            impl SubAssign<$t> for Number {
+            impl<T : FractionInteger> SubAssign<$t> for GenericNumber<T> {
                 fn sub_assign(&mut self, other: $t) {
-                    *self -= Number::Decimal(other as f64)
+                    *self -= GenericNumber::from(other)
                 }
             }
         )*
     };
     (mul => $($t : ty),*) => {
         $(
-            impl Mul<$t> for Number {
+            impl<T : FractionInteger> Mul<$t> for GenericNumber<T> {
                 type Output = Self;
                 fn mul(self, other: $t) -> Self {
                     match self {
-                        Number::Decimal(f) => Number::Decimal(f * other as f64),
-                        Number::StandardForm(sf) => Number::StandardForm(sf * other),
-                        Number::Fraction(fr) => (fr * other).into()
+                        GenericNumber::Decimal(f) => GenericNumber::Decimal(f * other as f64),
+                        GenericNumber::Integer(_) => self * GenericNumber::from(other),
+                        GenericNumber::StandardForm(_) => self * GenericNumber::Decimal(other as f64),
+                        GenericNumber::Fraction(_) => self * GenericNumber::Fraction(other.into()),
+                        #[cfg(feature="bigint")]
+                        GenericNumber::BigFraction(_) => self * GenericNumber::BigFraction(other.into()),
+                        GenericNumber::Symbolic(_) => self * GenericNumber::from(other),
                     }
                 }
             }
             
-            impl MulAssign<$t> for Number {
+            impl<T : FractionInteger> MulAssign<$t> for GenericNumber<T> {
                 fn mul_assign(&mut self, other: $t) {
-                    *self *= Number::Decimal(other as f64)
+                    *self *= GenericNumber::from(other)
                 }
             }
         )*
     };
     (div => $($t : ty),*) => {
         $(
-            impl Div<$t> for Number {
+            impl<T : FractionInteger> Div<$t> for GenericNumber<T> {
                 type Output = Self;
                 fn div(self, other: $t) -> Self {
                     match self {
-                        Number::Decimal(f) => Number::Decimal(f / other as f64),
-                        Number::StandardForm(sf) => Number::StandardForm(sf / other),
-                        Number::Fraction(fr) => (fr / other).into()
+                        GenericNumber::Decimal(f) => GenericNumber::Decimal(f / other as f64),
+                        GenericNumber::Integer(_) => self / GenericNumber::from(other),
+                        GenericNumber::StandardForm(_) => self / GenericNumber::Decimal(other as f64),
+                        GenericNumber::Fraction(_) => self / GenericNumber::Fraction(other.into()),
+                        #[cfg(feature="bigint")]
+                        GenericNumber::BigFraction(_) => self / GenericNumber::BigFraction(other.into()),
+                        GenericNumber::Symbolic(_) => self / GenericNumber::from(other),
                     }
                 }
             }
             
-            impl DivAssign<$t> for Number {
+            impl<T : FractionInteger> DivAssign<$t> for GenericNumber<T> {
                 fn div_assign(&mut self, other: $t) {
-                    *self /= Number::Decimal(other as f64)
+                    *self /= GenericNumber::from(other)
                 }
             }
         )*
     };
     (rem => $($t : ty),*) => {
         $(
-            impl Rem<$t> for Number {
+            impl<T : FractionInteger> Rem<$t> for GenericNumber<T> {
                 type Output = Self;
                 fn rem(self, other: $t) -> Self {
                     match self {
-                        Number::Decimal(f) => Number::Decimal(f % other as f64),
-                        Number::StandardForm(sf) => Number::StandardForm(sf % other),
-                        Number::Fraction(fr) => (fr % other).into()
+                        GenericNumber::Decimal(f) => GenericNumber::Decimal(f % other as f64),
+                        GenericNumber::Integer(_) => self % GenericNumber::from(other),
+                        GenericNumber::StandardForm(_) => self % GenericNumber::Decimal(other as f64),
+                        GenericNumber::Fraction(_) => self % GenericNumber::Fraction(other.into()),
+                        #[cfg(feature="bigint")]
+                        GenericNumber::BigFraction(_) => self % GenericNumber::BigFraction(other.into()),
+                        GenericNumber::Symbolic(_) => self % GenericNumber::from(other),
                     }
                 }
             }
             
-            impl RemAssign<$t> for Number {
+            impl<T : FractionInteger> RemAssign<$t> for GenericNumber<T> {
                 fn rem_assign(&mut self, other: $t) {
-                    *self %= Number::Decimal(other as f64)
+                    *self %= GenericNumber::from(other)
                 }
             }
         )*
@@ -439,12 +763,11 @@ macro_rules! primitives {
     (pow => $($t : ty),*) => {
         $(
             #[cfg(feature="num")]
-            impl num_traits::Pow<$t> for StandardForm {
-                type Output = f64;
-            
-                #[must_use]
+            impl<T : FractionInteger> num_traits::Pow<$t> for GenericNumber<T> {
+                type Output = Self;
+
                 fn pow(self, other: $t) -> Self::Output {
-                    f64::from(self).powf(other as f64)
+                    self.pow(GenericNumber::from(other))
                 }
             }
         )*
@@ -461,55 +784,85 @@ macro_rules! primitives {
     }
 }
 
-primitives!(eq => u8,u16,u32,u64,i8,i16,i32,i64,f32,f64);
-primitives!(ord => u8,u16,u32,u64,i8,i16,i32,i64,f32,f64);
-primitives!(operations => i8, i16, i32, i64, u8, u16, u32, u64,f32,f64);
+primitives!(eq => u8,u16,u32,u64,i8,i16,i32,i64,i128,f32,f64);
+primitives!(ord => u8,u16,u32,u64,i8,i16,i32,i64,i128,f32,f64);
+primitives!(operations => i8, i16, i32, i64, i128, u8, u16, u32, u64,f32,f64);
+
+/// Generates the method of `Number` for a function of a real number, while the functions of an angle are defined with `Angle`.
+macro_rules! number_function {
+    (angle $(#[$attr:meta])* $fn : ident) => {};
+    (real $(#[$attr:meta])* $fn : ident) => {
+        $(#[$attr])*
+        pub fn $fn <O : From<f64>>(self) -> O  {
+            f64::from(self). $fn ().into()
+        }
+    };
+}
 
 macro_rules! trig_functions {
     ($( {
-        $(#[$attr:meta])* $fn : ident
+        $(#[$attr:meta])* $kind : ident $fn : ident => |$x : ident| $derivative : expr , $shape : expr
     })*) => {
-        impl Number {
+        impl<T : FractionInteger> GenericNumber<T> {
+            $(
+                number_function!($kind $(#[$attr])* $fn);
+            )*
+        }
+
+        impl<T : FractionInteger> crate::GenericUncertain<T> {
             $(
                 $(#[$attr])*
-                pub fn $fn <T : From<f64>>(self) -> T  {
-                    f64::from(self). $fn ().into()
+                ///
+                /// The uncertainty is propagated through the derivative of the function at the central value.
+                pub fn $fn(self) -> Self {
+                    self.propagate(f64::$fn,|$x : f64| $derivative)
                 }
             )*
+        }
 
+        impl<T : FractionInteger> crate::GenericInterval<T> {
+            $(
+                $(#[$attr])*
+                ///
+                /// The bounds are rounded outward, so they contain the function of every number in the interval.
+                pub fn $fn(self) -> Self {
+                    self.apply(f64::$fn,$shape)
+                }
+            )*
         }
     };
 }
 
 trig_functions!(
     { /// Computes the sine of a number (in radians).
-      sin }
+      angle sin => |x| x.cos(), Shape::Periodic { peak : std::f64::consts::FRAC_PI_2 } }
     { /// Computes the cosine of a number (in radians).
-      cos }
+      angle cos => |x| -x.sin(), Shape::Periodic { peak : 0.0 } }
     { /// Computes the tangent of a number (in radians).
-     tan }
+     angle tan => |x| 1.0 / x.cos().powi(2), Shape::Tangent }
     { /// Computes the arcsine of a number.
-      asin }
+      real asin => |x| 1.0 / (1.0 - x * x).sqrt(), Shape::Increasing }
     { /// Computes the arccosine of a number.
-      acos }
+      real acos => |x| -1.0 / (1.0 - x * x).sqrt(), Shape::Decreasing }
     { /// Computes the arctangent of a number.
-      atan }
+      real atan => |x| 1.0 / (1.0 + x * x), Shape::Increasing }
     { /// Computes the hyperbolic sine.
-      sinh }
+      real sinh => |x| x.cosh(), Shape::Increasing }
     { /// Computes the hyperbolic cosine.
-     cosh }
+     real cosh => |x| x.sinh(), Shape::Even }
     { /// Computes the hyperbolic tangent.
-      tanh }
+      real tanh => |x| 1.0 / x.cosh().powi(2), Shape::Increasing }
     { /// Computes the inverse hyperbolic sine.
-      asinh }
+      real asinh => |x| 1.0 / (x * x + 1.0).sqrt(), Shape::Increasing }
     { /// Computes the inverse hyperbolic cosine.
-      acosh }
+      real acosh => |x| 1.0 / (x * x - 1.0).sqrt(), Shape::Increasing }
     { /// Computes the inverse hyperbolic tangent.
-      atanh }
+      real atanh => |x| 1.0 / (1.0 - x * x), Shape::Increasing }
 );
 
 
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod test {
     use super::*;
     #[test]
@@ -583,6 +936,103 @@ mod test {
         assert_eq!(num, Number::Decimal(5.0));
     }
 
+    #[test]
+    fn test_fraction_into_f64_rounds_to_nearest() {
+        assert_eq!(f64::from(Number::Fraction(GenericFraction::new(1u32,2u32))),0.5);
+        assert_eq!(f64::from(Number::Fraction(GenericFraction::new_neg(7u32,4u32))),-1.75);
+        assert_eq!(f64::from(Number::Fraction(GenericFraction::new(1u32,3u32))),1.0 / 3.0);
+        assert_eq!(f64::from(Number::Fraction(GenericFraction::new(u32::MAX,3u32))),u32::MAX as f64 / 3.0);
+    }
+
+    #[test]
+    fn test_ratio_to_f64_large_operands() {
+        assert_eq!(ratio_to_f64(u128::MAX,1),u128::MAX as f64);
+        assert_eq!(ratio_to_f64(1,3 << 100),1.0 / 3.0 / 2_f64.powi(100));
+        assert_eq!(ratio_to_f64((1 << 60) + 1,1 << 60),1.0);
+        assert_eq!(ratio_to_f64((1 << 60) + (1 << 8),1 << 7),2_f64.powi(53) + 2.0);
+    }
+
+    #[test]
+    fn test_to_f64_exact() {
+        assert_eq!(Number::Fraction(GenericFraction::new(3u32,8u32)).to_f64_exact(),Ok(0.375));
+        assert_eq!(Number::StandardForm(StandardForm::new(1.2,3)).to_f64_exact(),Ok(1200.0));
+        assert_eq!(Number::StandardForm(StandardForm::new(1.25,-1)).to_f64_exact(),Ok(0.125));
+        assert_eq!(Number::Decimal(0.1).to_f64_exact(),Ok(0.1));
+
+        let lossy = Number::Fraction(GenericFraction::new(1u32,3u32)).to_f64_exact().unwrap_err();
+        assert_eq!(lossy.approximation(),1.0 / 3.0);
+
+        let lossy = Number::StandardForm(StandardForm::new(1.2,-3)).to_f64_exact().unwrap_err();
+        assert_eq!(lossy.approximation(),0.0012);
+
+        assert!(Number::StandardForm(StandardForm::new(1.0,30)).to_f64_exact().is_err());
+
+        // the candidate float is compared by its exact binary value, which is not one tenth
+        let lossy = Number::Fraction(GenericFraction::new(1u32,10u32)).to_f64_exact().unwrap_err();
+        assert_eq!(lossy.approximation(),0.1);
+        assert_eq!(Number::Integer(1 << 60).to_f64_exact(),Ok(2_f64.powi(60)));
+        assert_eq!(Number::Integer((1 << 60) + 1).to_f64_exact().unwrap_err().approximation(),2_f64.powi(60));
+        assert_eq!(Number::StandardForm(StandardForm::new(1.0,30)).to_f64_exact().unwrap_err().approximation(),1e30);
+    }
+
+    #[test]
+    fn test_ratio_to_sf() {
+        let third = ratio_to_sf(1,3);
+        assert_eq!((*third.mantissa(),*third.exponent()),(10.0 / 3.0,-1));
+
+        let sf = ratio_to_sf(1200,1);
+        assert_eq!((*sf.mantissa(),*sf.exponent()),(1.2,3));
+
+        let sf = ratio_to_sf(7,40000);
+        assert_eq!((*sf.mantissa(),*sf.exponent()),(1.75,-4));
+
+        let sf = ratio_to_sf(u128::MAX,u128::MAX - 1);
+        assert_eq!((*sf.mantissa(),*sf.exponent()),(1.0,0));
+    }
+
+    #[test]
+    fn test_cross_variant_arithmetic_matrix() {
+        type Exact = GenericFraction<u64>;
+        type Operation = (&'static str,fn(Number,Number) -> Number,fn(Exact,Exact) -> Exact);
+
+        let operands = [
+            (Number::Fraction(GenericFraction::new(1u32,3u32)),Exact::new(1u64,3u64)),
+            (Number::Fraction(GenericFraction::new_neg(5u32,4u32)),Exact::new_neg(5u64,4u64)),
+            (Number::StandardForm(StandardForm::new(2.5,0)),Exact::new(5u64,2u64)),
+            (Number::StandardForm(StandardForm::new(-1.2,1)),Exact::new_neg(12u64,1u64)),
+            (Number::Decimal(0.75),Exact::new(3u64,4u64)),
+            (Number::Decimal(-6.0),Exact::new_neg(6u64,1u64)),
+        ];
+
+        let operations : [Operation;5] = [
+            ("+",|a,b| a + b,|a,b| a + b),
+            ("-",|a,b| a - b,|a,b| a - b),
+            ("*",|a,b| a * b,|a,b| a * b),
+            ("/",|a,b| a / b,|a,b| a / b),
+            ("%",|a,b| a % b,|a,b| a - b * (a / b).trunc()),
+        ];
+
+        for (lhs,exact_lhs) in &operands {
+            for (rhs,exact_rhs) in &operands {
+                for (symbol,operation,exact_operation) in &operations {
+                    let result = f64::from(operation(lhs.clone(),rhs.clone()));
+                    let expected = fraction::ToPrimitive::to_f64(&exact_operation(*exact_lhs,*exact_rhs)).unwrap();
+
+                    // an inexact divisor can land a remainder on either side of an exact multiple
+                    let error = match *symbol {
+                        "%" => (result - expected).abs().min(((result - expected).abs() - f64::from(rhs.clone()).abs()).abs()),
+                        _ => (result - expected).abs()
+                    };
+
+                    assert!(
+                        error <= 1e-6 * (1.0 + expected.abs()),
+                        "{lhs:?} {symbol} {rhs:?} gave {result} instead of {expected}"
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_display_decimal() {
         let number = Number::Decimal(3.14);
@@ -601,7 +1051,7 @@ mod test {
         if let Ok(Number::Decimal(value)) = result {
             assert_eq!(value, 3.14);
         } else {
-            assert!(false, "Expected Ok(Number::Decimal(_)), but got an error.");
+            panic!("Expected Ok(Number::Decimal(_)), but got an error.");
         }
     }
 
@@ -613,9 +1063,8 @@ mod test {
         assert!(result.is_err());
 
         // Check if the correct error variant is returned
-        if let Err(_) = result {
-        } else {
-            assert!(false, "Expected Err(ParseFloatError), but got a success.");
+        if result.is_ok() {
+            panic!("Expected Err(ParseFloatError), but got a success.");
         }
     }
 
@@ -627,9 +1076,93 @@ mod test {
         assert!(result.is_err());
 
         // Check if the correct error variant is returned
-        if let Err(_) = result {
-        } else {
-            assert!(false, "Expected Err(ParseFloatError), but got a success.");
+        if result.is_ok() {
+            panic!("Expected Err(ParseFloatError), but got a success.");
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_generic_u64_fraction_arithmetic() {
+        let big = GenericNumber::<u64>::Fraction(GenericFraction::new(5_000_000_000u64, 3u64));
+        let one = GenericNumber::<u64>::Fraction(GenericFraction::new(1u64, 3u64));
+
+        assert_eq!(big.clone() + one.clone(), GenericNumber::Fraction(GenericFraction::new(5_000_000_001u64, 3u64)));
+        assert_eq!(big.clone() - one.clone(), GenericNumber::Fraction(GenericFraction::new(4_999_999_999u64, 3u64)));
+        assert_eq!(big.clone() * GenericNumber::Decimal(3.0), GenericNumber::Fraction(GenericFraction::new(5_000_000_000u64, 1u64)));
+        assert_eq!(big / one, GenericNumber::Fraction(GenericFraction::new(5_000_000_000u64, 1u64)));
+    }
+
+    #[test]
+    fn test_generic_i128_fraction_sign() {
+        let negative = GenericNumber::<i128>::Fraction(GenericFraction::new(-3i128, 4i128));
+
+        assert_eq!(f64::from(negative.clone()), -0.75);
+        assert_eq!(negative.clone(), GenericNumber::<i128>::Decimal(-0.75));
+        assert!(negative.clone() < GenericNumber::Decimal(0.0));
+        assert_eq!(negative + GenericNumber::Decimal(1.0), GenericNumber::Fraction(GenericFraction::new(1i128, 4i128)));
+    }
+
+    #[test]
+    fn test_generic_u16_try_from() {
+        assert_eq!(GenericNumber::<u16>::try_from("3/4").unwrap(), GenericNumber::Fraction(GenericFraction::new(3u16, 4u16)));
+        assert_eq!(GenericNumber::<i128>::try_from("-3/4").unwrap(), GenericNumber::Fraction(GenericFraction::new_neg(3i128, 4i128)));
+    }
+
+    #[test]
+    fn test_integer_arithmetic_is_exact() {
+        let big = Number::Integer(1 << 60);
+
+        assert_eq!((big.clone() + Number::Integer(1)).to_string(), "1152921504606846977");
+        assert_eq!(big.clone() * Number::Integer(3) - big.clone(), Number::Integer(1 << 61));
+        assert!((big.clone() / Number::Integer(1 << 20)).structural_eq(&Number::Integer(1 << 40)));
+        assert!((Number::Integer(7) % Number::Integer(-3)).structural_eq(&Number::Integer(1)));
+        assert_eq!(big + 1u64, Number::Integer((1 << 60) + 1));
+    }
+
+    #[test]
+    fn test_integer_division_promotes_to_fraction() {
+        let quotient = Number::Integer(3) / Number::Integer(4);
+        assert!(quotient.structural_eq(&Number::Fraction(GenericFraction::new(3u32, 4u32))));
+
+        let sum = Number::Integer(1) + Number::Fraction(GenericFraction::new(1u32, 2u32));
+        assert!(sum.structural_eq(&Number::Fraction(GenericFraction::new(3u32, 2u32))));
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        let product = Number::Integer(i128::MAX) * Number::Integer(2);
+        #[cfg(not(feature="bigint"))]
+        assert!(product.structural_eq(&Number::Decimal(i128::MAX as f64 * 2.0)));
+        #[cfg(feature="bigint")]
+        assert_eq!(product.to_string(), "340282366920938463463374607431768211454");
+
+        assert_eq!(-Number::Integer(i128::MIN), Number::Decimal(2_f64.powi(127)));
+    }
+
+    #[test]
+    fn test_integer_compares_exactly() {
+        let big = Number::Integer((1 << 60) + 1);
+
+        assert!(big > Number::Decimal(2_f64.powi(60)));
+        assert_eq!(Number::Integer(1 << 60), Number::Decimal(2_f64.powi(60)));
+        assert!(Number::Integer(-2) < Number::Decimal(-1.5));
+        assert!(Number::Integer(-1) > Number::Decimal(-1.5));
+        assert_eq!(Number::Integer(-1), Number::Decimal(-1.0));
+        assert_eq!(Number::Integer(2), Number::Fraction(GenericFraction::new(4u32, 2u32)));
+        assert!(Number::Integer(1) < Number::Fraction(GenericFraction::new(3u32, 2u32)));
+        assert!(Number::Integer(i128::MAX) < Number::Decimal(f64::INFINITY));
+        assert_eq!(big, (1u64 << 60) + 1);
+    }
+
+    #[test]
+    fn test_try_from_integer() {
+        let result = Number::try_from("-170141183460469231731687303715884105728").unwrap();
+        assert!(result.structural_eq(&Number::Integer(i128::MIN)));
+
+        assert!(Number::try_from("5").unwrap().structural_eq(&Number::Integer(5)));
+        assert!(Number::try_from("9007199254740993").unwrap().structural_eq(&Number::Integer((1 << 53) + 1)));
+        assert!(Number::try_from("5.0").unwrap().structural_eq(&Number::Decimal(5.0)));
+        assert!(Number::try_from("5e0").unwrap().structural_eq(&Number::Decimal(5.0)));
+        assert!(Number::try_from("170141183460469231731687303715884105728").unwrap().structural_eq(&Number::Decimal(2_f64.powi(127))));
+    }
+}
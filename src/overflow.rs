@@ -0,0 +1,175 @@
+use standardform::StandardForm;
+use fraction::GenericFraction;
+
+use crate::{GenericNumber,FractionInteger,ArithmeticError,PromotionPolicy};
+use crate::number::float_to_sf;
+use crate::promotion::{checked_operate,operate,sf_range,Notation};
+use crate::special::Operation;
+
+/// Decides what arithmetic does when its result is too large for the variant that `PromotionPolicy::PreferExact` picks.
+///
+/// A fraction overflows when its numerator or denominator does not fit into `T`. This includes the results
+/// that the `bigint` feature would hold in `Number::BigFraction`.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+enum OverflowPolicy {
+    Saturate,
+    Demote
+}
+
+/// The largest mantissa below ten, so that standard form stays normalised.
+const LARGEST_MANTISSA : f64 = 9.999999999999998;
+
+impl OverflowPolicy {
+    fn resolve<T : FractionInteger>(self,notation : Notation,nearest : f64) -> GenericNumber<T> {
+        match (self,notation) {
+            (OverflowPolicy::Saturate,Notation::Integer) => GenericNumber::Integer(match nearest.is_sign_negative() {
+                true => i128::MIN,
+                false => i128::MAX
+            }),
+            (OverflowPolicy::Saturate,Notation::Fraction) => {
+                let max = T::max_value();
+                match nearest.abs() > max.to_f64().unwrap_or(f64::INFINITY) {
+                    true if nearest.is_sign_negative() => GenericNumber::Fraction(GenericFraction::new_neg(max,T::one())),
+                    true => GenericNumber::Fraction(GenericFraction::new(max,T::one())),
+                    // the fraction only overflows because of its denominator
+                    false => GenericNumber::Decimal(nearest)
+                }
+            },
+            (OverflowPolicy::Saturate,Notation::Decimal) => GenericNumber::Decimal(f64::MAX.copysign(nearest)),
+            (OverflowPolicy::Saturate,Notation::StandardForm) => GenericNumber::StandardForm(StandardForm::new(LARGEST_MANTISSA.copysign(nearest),i8::MAX)),
+            (OverflowPolicy::Demote,Notation::Integer | Notation::Fraction) if sf_range(nearest).is_ok() => GenericNumber::StandardForm(float_to_sf(nearest)),
+            (OverflowPolicy::Demote,_) => GenericNumber::Decimal(nearest)
+        }
+    }
+}
+
+fn overflowing_operate<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>,overflow : OverflowPolicy) -> GenericNumber<T> {
+    let policy = PromotionPolicy::default();
+    let notation = policy.notation(lhs,rhs);
+
+    let nearest = match checked_operate(operation,lhs,rhs,policy) {
+        #[cfg(feature="bigint")]
+        Ok(GenericNumber::BigFraction(fr)) => crate::bigint::big_fraction_to_f64(&fr),
+        Ok(result) => return result,
+        Err(ArithmeticError::Overflow) => operation.apply(lhs.clone().into(),rhs.clone().into()),
+        // only overflow is handled differently, everything else behaves like the operators
+        Err(_) => return operate(operation,lhs,rhs,policy)
+    };
+
+    overflow.resolve(notation,nearest)
+}
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Adds two numbers, clamping the result instead of overflowing.
+    ///
+    /// Integers clamp to `i128::MIN` or `i128::MAX`, decimals to `f64::MIN` or `f64::MAX` and numbers in standard form
+    /// to the largest mantissa with an exponent of `i8::MAX`. A fraction larger than the largest fraction of `T`
+    /// clamps to that fraction, while a fraction that overflows because of its denominator is the nearest decimal.
+    pub fn saturating_add(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Add,self,rhs,OverflowPolicy::Saturate)
+    }
+
+    /// Subtracts `rhs` from the number, clamping the result instead of overflowing like `saturating_add`.
+    pub fn saturating_sub(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Sub,self,rhs,OverflowPolicy::Saturate)
+    }
+
+    /// Multiplies two numbers, clamping the result instead of overflowing like `saturating_add`.
+    pub fn saturating_mul(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Mul,self,rhs,OverflowPolicy::Saturate)
+    }
+
+    /// Divides the number by `rhs`, clamping the result instead of overflowing like `saturating_add`.
+    pub fn saturating_div(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Div,self,rhs,OverflowPolicy::Saturate)
+    }
+
+    /// Computes the remainder of dividing the number by `rhs`, clamping the result instead of overflowing like `saturating_add`.
+    pub fn saturating_rem(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Rem,self,rhs,OverflowPolicy::Saturate)
+    }
+
+    /// Adds two numbers, converting an integer or fraction that would overflow into standard form.
+    ///
+    /// Results out of the range of standard form are decimals, as are numbers in standard form that overflow.
+    pub fn demoting_add(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Add,self,rhs,OverflowPolicy::Demote)
+    }
+
+    /// Subtracts `rhs` from the number, converting a result that would overflow like `demoting_add`.
+    pub fn demoting_sub(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Sub,self,rhs,OverflowPolicy::Demote)
+    }
+
+    /// Multiplies two numbers, converting a result that would overflow like `demoting_add`.
+    pub fn demoting_mul(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Mul,self,rhs,OverflowPolicy::Demote)
+    }
+
+    /// Divides the number by `rhs`, converting a result that would overflow like `demoting_add`.
+    pub fn demoting_div(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Div,self,rhs,OverflowPolicy::Demote)
+    }
+
+    /// Computes the remainder of dividing the number by `rhs`, converting a result that would overflow like `demoting_add`.
+    pub fn demoting_rem(&self,rhs : &GenericNumber<T>) -> GenericNumber<T> {
+        overflowing_operate(Operation::Rem,self,rhs,OverflowPolicy::Demote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Number;
+
+    use super::*;
+
+    fn max() -> Number {
+        Number::Fraction(GenericFraction::new(u32::MAX,1u32))
+    }
+
+    fn tiny() -> Number {
+        Number::Fraction(GenericFraction::new(1u32,u32::MAX))
+    }
+
+    #[test]
+    fn test_saturating_fractions() {
+        assert!(max().saturating_add(&max()).structural_eq(&max()));
+        assert!((-max()).saturating_mul(&max()).structural_eq(&-max()));
+
+        let product = tiny().saturating_mul(&tiny());
+        assert!(product.structural_eq(&Number::Decimal(1.0 / (u32::MAX as f64 * u32::MAX as f64))));
+
+        let third = Number::Fraction(GenericFraction::new(1u32,3u32));
+        assert!(third.saturating_add(&third).structural_eq(&Number::Fraction(GenericFraction::new(2u32,3u32))));
+    }
+
+    #[test]
+    fn test_saturating_other_variants() {
+        assert!(Number::Integer(i128::MAX).saturating_add(&Number::Integer(1)).structural_eq(&Number::Integer(i128::MAX)));
+        assert!(Number::Integer(i128::MIN).saturating_sub(&Number::Integer(1)).structural_eq(&Number::Integer(i128::MIN)));
+        assert!(Number::Decimal(f64::MAX).saturating_mul(&Number::Decimal(-2.0)).structural_eq(&Number::Decimal(f64::MIN)));
+
+        let huge = Number::StandardForm(StandardForm::new(1.0,100));
+        assert!(huge.saturating_mul(&huge).structural_eq(&Number::StandardForm(StandardForm::new(LARGEST_MANTISSA,i8::MAX))));
+
+        // results that do not overflow behave like the operators
+        assert!(Number::Decimal(1.0).saturating_div(&Number::Integer(0)).structural_eq(&Number::Decimal(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_demoting() {
+        let squared = max().demoting_mul(&max());
+        assert!(matches!(squared,Number::StandardForm(_)));
+        assert_eq!(f64::from(squared),u32::MAX as f64 * u32::MAX as f64);
+
+        assert!(matches!(tiny().demoting_mul(&tiny()),Number::StandardForm(_)));
+        assert!(matches!(Number::Integer(i128::MAX).demoting_add(&Number::Integer(1)),Number::StandardForm(_)));
+
+        let huge = Number::StandardForm(StandardForm::new(1.0,100));
+        let nearest = f64::from(huge.clone()) * f64::from(huge.clone());
+        assert!(huge.demoting_mul(&huge).structural_eq(&Number::Decimal(nearest)));
+
+        let half = Number::Fraction(GenericFraction::new(1u32,2u32));
+        assert!(half.demoting_add(&half).structural_eq(&Number::Fraction(GenericFraction::new(1u32,1u32))));
+    }
+}
@@ -0,0 +1,171 @@
+use fraction::GenericFraction;
+use standardform::StandardForm;
+
+use crate::{GenericNumber,FractionInteger,ArithmeticError,GenericSymbolic};
+use crate::checked::integer_pow;
+use crate::number::to_u128_fraction;
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Raises the number to an integer power.
+    ///
+    /// Integers and fractions stay exact, so `(2/3)^-2` is `9/4`. Numbers in standard form raise their mantissa
+    /// and multiply their exponent separately, so the result does not overflow until its exponent leaves the range
+    /// of standard form, where it becomes the nearest decimal. Multiples of `π` and `e` raise their coefficient
+    /// and multiply the powers of the constants, so `(2π)^2` is `4π^2`. Decimals are raised with `f64::powi`.
+    pub fn powi(&self,power : i32) -> GenericNumber<T> {
+        match self {
+            GenericNumber::Decimal(d) => GenericNumber::Decimal(d.powi(power)),
+            GenericNumber::StandardForm(sf) => sf_powi(sf,power),
+            GenericNumber::Symbolic(symbolic) => checked_symbolic_power(symbolic,power)
+                .unwrap_or_else(|_| GenericNumber::Decimal(symbolic.to_f64().powi(power))),
+            number => match exact_power(number,power.unsigned_abs()) {
+                Some(result) if power < 0 => GenericNumber::Integer(1) / result,
+                Some(result) => result,
+                None => GenericNumber::Decimal(f64::from(number.clone()).powi(power))
+            }
+        }
+    }
+}
+
+/// Raises an integer or fraction to a non-negative power exactly, returning `None` for other numbers, NaN and infinities.
+///
+/// Results that do not fit into their variant are big fractions with the `bigint` feature, and the nearest decimal otherwise.
+pub(crate) fn exact_power<T : FractionInteger>(number : &GenericNumber<T>,power : u32) -> Option<GenericNumber<T>> {
+    checked_exact_power(number,power).map(|result| result.unwrap_or_else(|_| GenericNumber::Decimal(f64::from(number.clone()).powf(power as f64))))
+}
+
+/// Raises an integer or fraction to a non-negative power exactly like `exact_power`, failing with
+/// `ArithmeticError::Overflow` instead of becoming a decimal when the result does not fit.
+pub(crate) fn checked_exact_power<T : FractionInteger>(number : &GenericNumber<T>,power : u32) -> Option<Result<GenericNumber<T>,ArithmeticError>> {
+    match number {
+        GenericNumber::Integer(i) => Some(integer_pow(*i,power)),
+        GenericNumber::Fraction(fr) => {
+            let GenericFraction::Rational(sign,ratio) = to_u128_fraction(fr)? else {
+                return None;
+            };
+
+            let parts = ratio.numer().checked_pow(power)
+                .zip(ratio.denom().checked_pow(power))
+                .and_then(|(numer,denom)| T::from_u128(numer).zip(T::from_u128(denom)));
+
+            match parts {
+                Some((numer,denom)) if sign.is_negative() && power % 2 == 1 => Some(Ok(GenericNumber::Fraction(GenericFraction::new_neg(numer,denom)))),
+                Some((numer,denom)) => Some(Ok(GenericNumber::Fraction(GenericFraction::new(numer,denom)))),
+                None => Some(wide_fraction_pow(fr,power))
+            }
+        },
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(fr) => crate::bigint::big_fraction_pow(fr,power).map(|power| Ok(crate::bigint::narrow(power))),
+        _ => None
+    }
+}
+
+/// Raises a multiple of the constants to an integer power, failing with `ArithmeticError::Overflow`
+/// if a power of the constants does not fit into an `i32` or the coefficient overflows.
+pub(crate) fn checked_symbolic_power<T : FractionInteger>(symbolic : &GenericSymbolic<T>,power : i32) -> Result<GenericNumber<T>,ArithmeticError> {
+    let pi = symbolic.pi_power().checked_mul(power).ok_or(ArithmeticError::Overflow)?;
+    let e = symbolic.e_power().checked_mul(power).ok_or(ArithmeticError::Overflow)?;
+    let coefficient = symbolic.coefficient().checked_pow(&GenericNumber::Integer(power.into()))?;
+    Ok(GenericSymbolic::new(coefficient,pi,e).into())
+}
+
+/// Raises a fraction whose power does not fit into `T` exactly as a big fraction.
+#[cfg(feature="bigint")]
+fn wide_fraction_pow<T : FractionInteger>(fr : &GenericFraction<T>,power : u32) -> Result<GenericNumber<T>,ArithmeticError> {
+    let power = crate::bigint::big_fraction_pow(&crate::bigint::widen(fr),power).expect("the fraction is rational");
+    Ok(crate::bigint::narrow(power))
+}
+
+/// Fails to raise a fraction whose power does not fit into `T`, as only big fractions could hold it.
+#[cfg(not(feature="bigint"))]
+fn wide_fraction_pow<T : FractionInteger>(_ : &GenericFraction<T>,_ : u32) -> Result<GenericNumber<T>,ArithmeticError> {
+    Err(ArithmeticError::Overflow)
+}
+
+/// Raises a number in standard form to an integer power by squaring its mantissa and doubling its exponent.
+pub(crate) fn sf_powi<T : FractionInteger>(sf : &StandardForm,power : i32) -> GenericNumber<T> {
+    let (mantissa,exponent) = (*sf.mantissa(),*sf.exponent() as i64);
+    if mantissa == 0.0 || !mantissa.is_finite() {
+        return GenericNumber::Decimal(f64::from(sf.clone()).powi(power));
+    }
+
+    let mut base = match power < 0 {
+        true => normalized(1.0 / mantissa,-exponent),
+        false => (mantissa,exponent)
+    };
+    let (mut result,mut remaining) = ((1.0,0),power.unsigned_abs());
+
+    while remaining > 0 {
+        if remaining % 2 == 1 {
+            result = normalized(result.0 * base.0,result.1 + base.1);
+        }
+        remaining /= 2;
+        if remaining > 0 {
+            base = normalized(base.0 * base.0,base.1 * 2);
+        }
+    }
+
+    let (mantissa,exponent) = result;
+    match i8::try_from(exponent) {
+        Ok(exponent) => GenericNumber::StandardForm(StandardForm::new(mantissa,exponent)),
+        Err(_) => GenericNumber::Decimal(format!("{mantissa}e{exponent}").parse().expect("a mantissa with an exponent forms a valid float"))
+    }
+}
+
+/// Moves powers of ten from a mantissa into its exponent, so the mantissa lies between one and ten.
+fn normalized(mantissa : f64,exponent : i64) -> (f64,i64) {
+    let shift = mantissa.abs().log10().floor() as i32;
+    (mantissa / 10_f64.powi(shift),exponent + shift as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Number;
+
+    use super::*;
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_exact_powers() {
+        assert!(fraction(2,3).powi(3).structural_eq(&fraction(8,27)));
+        assert!(fraction(2,3).powi(-2).structural_eq(&fraction(9,4)));
+        assert!(Number::Fraction(GenericFraction::new_neg(1u32,2u32)).powi(3).structural_eq(&Number::Fraction(GenericFraction::new_neg(1u32,8u32))));
+        assert!(Number::Integer(-3).powi(3).structural_eq(&Number::Integer(-27)));
+        assert!(Number::Integer(2).powi(-3).structural_eq(&fraction(1,8)));
+        assert!(Number::Integer(7).powi(0).structural_eq(&Number::Integer(1)));
+    }
+
+    #[test]
+    fn test_overflowing_powers() {
+        let squared = fraction(u32::MAX,1).powi(2);
+        assert_eq!(f64::from(squared.clone()),u32::MAX as f64 * u32::MAX as f64);
+        assert!(!matches!(squared,Number::Fraction(_)));
+    }
+
+    #[test]
+    fn test_standard_form_powers() {
+        let squared = Number::StandardForm(StandardForm::new(1.2,3)).powi(2);
+        assert!(squared.structural_eq(&Number::StandardForm(StandardForm::new(1.2 * 1.2,6))));
+
+        let reciprocal = Number::StandardForm(StandardForm::new(5.0,-100)).powi(-1);
+        assert!(reciprocal.structural_eq(&Number::StandardForm(StandardForm::new(2.0,99))));
+
+        // exponents that leave the range of standard form become decimals, which may overflow in turn
+        assert!(Number::StandardForm(StandardForm::new(1.0,100)).powi(2).structural_eq(&Number::Decimal(1e200)));
+        assert!(Number::StandardForm(StandardForm::new(2.0,50)).powi(10).structural_eq(&Number::Decimal(f64::INFINITY)));
+    }
+
+    #[cfg(feature="num")]
+    #[test]
+    fn test_pow_with_integer_exponents() {
+        use num_traits::Pow;
+
+        assert!(fraction(2,3).pow(Number::Decimal(3.0)).structural_eq(&fraction(8,27)));
+        assert!(fraction(2,3).pow(-1_i32).structural_eq(&fraction(3,2)));
+        assert!(fraction(4,9).pow(fraction(3,2)).structural_eq(&fraction(8,27)));
+        assert!(Number::Decimal(2.0).pow(Number::Integer(3)).structural_eq(&Number::Decimal(8.0)));
+    }
+}
@@ -0,0 +1,475 @@
+use standardform::StandardForm;
+
+use crate::{GenericNumber,FractionInteger,ArithmeticError};
+use crate::number::{fraction_to_sf,ratio_to_sf,sf_rem,to_integer};
+use crate::special::{special_value,is_zero,Operation};
+use crate::symbolic::{symbolic_operation,evaluated,checked_evaluated,is_symbolic};
+
+// with the `bigint` feature fractions are computed as big fractions, so they cannot overflow
+#[cfg(not(feature="bigint"))]
+use crate::number::to_fraction as to_exact;
+
+#[cfg(feature="bigint")]
+use crate::bigint::{to_big_fraction as to_exact,narrow as from_exact};
+
+#[cfg(not(feature="bigint"))]
+fn from_exact<T : FractionInteger>(fraction : fraction::GenericFraction<T>) -> GenericNumber<T> {
+    GenericNumber::Fraction(fraction)
+}
+
+/// Decides which variant holds the result of arithmetic between two numbers.
+///
+/// The arithmetic operators on `Number` use `PromotionPolicy::default()`, which is `PreferExact`.
+/// Standard form cannot hold NaN, infinities or magnitudes outside of 10^-128 up to 10^128, so those results
+/// are decimals whenever a policy asks for standard form, or big fractions with the `bigint` feature if they are exact.
+///
+/// Whenever a policy asks for an integer, a quotient that is not an integer is a fraction, and a result
+/// that does not fit into an `i128` is a decimal, or a big fraction with the `bigint` feature.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,Default)]
+pub enum PromotionPolicy {
+    /// If either operand is a fraction and the other can be converted into one without loss,
+    /// the result is a fraction. Otherwise the result is an integer if both operands are,
+    /// in standard form if either operand is, and a decimal if neither is.
+    #[default]
+    PreferExact,
+
+    /// The result takes the variant of the left operand. If that is an integer or a fraction and the right operand
+    /// cannot be converted into one without loss, the result takes the variant of the right operand instead.
+    PreferLeft,
+
+    /// The result is always a decimal.
+    PreferDecimal,
+
+    /// The result is always in standard form.
+    PreferStandardForm,
+}
+
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub(crate) enum Notation {
+    Integer,
+    Decimal,
+    StandardForm,
+    Fraction
+}
+
+impl Notation {
+    fn of<T : FractionInteger>(number : &GenericNumber<T>) -> Self {
+        match number {
+            GenericNumber::Integer(_) => Notation::Integer,
+            GenericNumber::Decimal(_) => Notation::Decimal,
+            GenericNumber::StandardForm(_) => Notation::StandardForm,
+            GenericNumber::Fraction(_) => Notation::Fraction,
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(_) => Notation::Fraction,
+            // arithmetic that cannot keep the constants evaluates them into a decimal
+            GenericNumber::Symbolic(_) => Notation::Decimal,
+        }
+    }
+
+    /// Returns `true` if the number can be held in this notation without loss.
+    fn holds<T : FractionInteger>(self,number : &GenericNumber<T>) -> bool {
+        match self {
+            Notation::Integer => to_integer(number).is_some(),
+            Notation::Fraction => to_exact(number).is_some(),
+            Notation::Decimal | Notation::StandardForm => true
+        }
+    }
+}
+
+impl PromotionPolicy {
+    pub(crate) fn notation<T : FractionInteger>(self,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Notation {
+        let preferred = match self {
+            PromotionPolicy::PreferExact => match (Notation::of(lhs),Notation::of(rhs)) {
+                (Notation::Fraction,_) | (_,Notation::Fraction) => Notation::Fraction,
+                (Notation::Integer,Notation::Integer) => Notation::Integer,
+                (Notation::StandardForm,_) | (_,Notation::StandardForm) => Notation::StandardForm,
+                _ => Notation::Decimal
+            },
+            PromotionPolicy::PreferLeft => Notation::of(lhs),
+            PromotionPolicy::PreferDecimal => Notation::Decimal,
+            PromotionPolicy::PreferStandardForm => Notation::StandardForm,
+        };
+
+        let holds_both = |notation : Notation| notation.holds(lhs) && notation.holds(rhs);
+
+        if holds_both(preferred) {
+            return preferred;
+        }
+
+        // rather than rounding an operand into an integer or a fraction, use the variant of that operand
+        let fallback = match preferred.holds(lhs) {
+            true => Notation::of(rhs),
+            false => Notation::of(lhs)
+        };
+
+        match holds_both(fallback) {
+            true => fallback,
+            false => Notation::Decimal
+        }
+    }
+}
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Adds two numbers, choosing the variant of the result with the given policy.
+    pub fn add_with(&self,rhs : &GenericNumber<T>,policy : PromotionPolicy) -> GenericNumber<T> {
+        operate(Operation::Add,self,rhs,policy)
+    }
+
+    /// Subtracts `rhs` from the number, choosing the variant of the result with the given policy.
+    pub fn sub_with(&self,rhs : &GenericNumber<T>,policy : PromotionPolicy) -> GenericNumber<T> {
+        operate(Operation::Sub,self,rhs,policy)
+    }
+
+    /// Multiplies two numbers, choosing the variant of the result with the given policy.
+    pub fn mul_with(&self,rhs : &GenericNumber<T>,policy : PromotionPolicy) -> GenericNumber<T> {
+        operate(Operation::Mul,self,rhs,policy)
+    }
+
+    /// Divides the number by `rhs`, choosing the variant of the result with the given policy.
+    pub fn div_with(&self,rhs : &GenericNumber<T>,policy : PromotionPolicy) -> GenericNumber<T> {
+        operate(Operation::Div,self,rhs,policy)
+    }
+
+    /// Computes the remainder of dividing the number by `rhs`, choosing the variant of the result with the given policy.
+    pub fn rem_with(&self,rhs : &GenericNumber<T>,policy : PromotionPolicy) -> GenericNumber<T> {
+        operate(Operation::Rem,self,rhs,policy)
+    }
+}
+
+pub(crate) fn operate<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>,policy : PromotionPolicy) -> GenericNumber<T> {
+    if is_symbolic(lhs) || is_symbolic(rhs) {
+        return symbolic_operation(operation,lhs,rhs,policy)
+            .unwrap_or_else(|| operate(operation,&evaluated(lhs),&evaluated(rhs),policy));
+    }
+
+    let notation = policy.notation(lhs,rhs);
+
+    if let Some(special) = special_value(operation,lhs,rhs) {
+        return convert(special,notation);
+    }
+
+    match notation {
+        Notation::Integer => {
+            let lhs_integer = to_integer(lhs).expect("the policy only picks integers for integer operands");
+            let rhs_integer = to_integer(rhs).expect("the policy only picks integers for integer operands");
+            match integer_operation(operation,lhs_integer,rhs_integer) {
+                Some(integer) => GenericNumber::Integer(integer),
+                // an uneven quotient is a fraction, while an overflowing result only stays exact as a big fraction
+                None if operation == Operation::Div || cfg!(feature="bigint") => exact_operation(operation,lhs,rhs)
+                    .unwrap_or_else(|| GenericNumber::Decimal(operation.apply(lhs.clone().into(),rhs.clone().into()))),
+                None => GenericNumber::Decimal(operation.apply(lhs.clone().into(),rhs.clone().into()))
+            }
+        },
+        Notation::Decimal => GenericNumber::Decimal(operation.apply(lhs.clone().into(),rhs.clone().into())),
+        Notation::StandardForm => match checked_sf_operation(operation,lhs,rhs) {
+            Ok(sf) => GenericNumber::StandardForm(sf),
+            // a result out of the range of standard form only stays exact as a big fraction
+            Err(_) if cfg!(feature="bigint") => exact_operation(operation,lhs,rhs)
+                .unwrap_or_else(|| GenericNumber::Decimal(operation.apply(lhs.clone().into(),rhs.clone().into()))),
+            Err(_) => GenericNumber::Decimal(operation.apply(lhs.clone().into(),rhs.clone().into()))
+        },
+        Notation::Fraction => exact_operation(operation,lhs,rhs).expect("the policy only picks fractions for exact operands")
+    }
+}
+
+/// Applies an operation like `operate`, but fails instead of producing NaN or an infinity from finite operands,
+/// overflowing an integer or fraction, or rounding a non-zero result to zero.
+pub(crate) fn checked_operate<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>,policy : PromotionPolicy) -> Result<GenericNumber<T>,ArithmeticError> {
+    if lhs.is_nan() || rhs.is_nan() {
+        return Err(ArithmeticError::Domain);
+    }
+
+    if matches!(operation,Operation::Div | Operation::Rem) && is_zero(rhs) {
+        return Err(ArithmeticError::DivisionByZero);
+    }
+
+    if is_symbolic(lhs) || is_symbolic(rhs) {
+        return match symbolic_operation(operation,lhs,rhs,policy) {
+            Some(result) => Ok(result),
+            None => checked_operate(operation,&checked_evaluated(lhs)?,&checked_evaluated(rhs)?,policy)
+        };
+    }
+
+    let notation = policy.notation(lhs,rhs);
+
+    if let Some(special) = special_value(operation,lhs,rhs) {
+        return match special.is_nan() {
+            true => Err(ArithmeticError::Domain),
+            false => Ok(convert(special,notation))
+        };
+    }
+
+    let result = match notation {
+        Notation::Integer => {
+            let lhs_integer = to_integer(lhs).expect("the policy only picks integers for integer operands");
+            let rhs_integer = to_integer(rhs).expect("the policy only picks integers for integer operands");
+            match integer_operation(operation,lhs_integer,rhs_integer) {
+                Some(integer) => GenericNumber::Integer(integer),
+                None if cfg!(feature="bigint") => checked_exact_operation(operation,lhs,rhs)?,
+                // the quotient is uneven rather than overflowing
+                None if operation == Operation::Div && lhs_integer.checked_rem(rhs_integer).is_some() => checked_exact_operation(operation,lhs,rhs)?,
+                None => return Err(ArithmeticError::Overflow)
+            }
+        },
+        Notation::Decimal => GenericNumber::Decimal(operation.apply(lhs.clone().into(),rhs.clone().into())),
+        Notation::StandardForm => GenericNumber::StandardForm(checked_sf_operation(operation,lhs,rhs)?),
+        Notation::Fraction => checked_exact_operation(operation,lhs,rhs)?
+    };
+
+    let vanishes = match operation {
+        Operation::Mul => !is_zero(lhs) && !is_zero(rhs),
+        Operation::Div => !is_zero(lhs),
+        _ => false
+    };
+
+    if result.is_infinite() {
+        Err(ArithmeticError::Overflow)
+    } else if vanishes && is_zero(&result) {
+        Err(ArithmeticError::PrecisionLoss)
+    } else {
+        Ok(result)
+    }
+}
+
+/// Applies an operation to two integers, returning `None` if the result is not an integer that fits into an `i128`.
+fn integer_operation(operation : Operation,lhs : i128,rhs : i128) -> Option<i128> {
+    match operation {
+        Operation::Add => lhs.checked_add(rhs),
+        Operation::Sub => lhs.checked_sub(rhs),
+        Operation::Mul => lhs.checked_mul(rhs),
+        Operation::Div => match lhs.checked_rem(rhs)? {
+            0 => lhs.checked_div(rhs),
+            _ => None
+        },
+        // only `i128::MIN % -1` overflows, and its remainder is zero
+        Operation::Rem => Some(lhs.wrapping_rem(rhs)),
+    }
+}
+
+/// Applies an operation to the fractions of two numbers, returning `None` if either of them has no exact fraction.
+fn exact_operation<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Option<GenericNumber<T>> {
+    let (lhs,rhs) = (to_exact(lhs)?,to_exact(rhs)?);
+    Some(from_exact(match operation {
+        Operation::Add => lhs + rhs,
+        Operation::Sub => lhs - rhs,
+        Operation::Mul => lhs * rhs,
+        Operation::Div => lhs / rhs,
+        Operation::Rem => lhs % rhs,
+    }))
+}
+
+fn convert<T : FractionInteger>(number : GenericNumber<T>,notation : Notation) -> GenericNumber<T> {
+    match notation {
+        Notation::Integer => match to_integer(&number) {
+            Some(integer) => GenericNumber::Integer(integer),
+            None => number
+        },
+        Notation::Fraction => match to_exact(&number) {
+            Some(fraction) => from_exact(fraction),
+            None => number
+        },
+        Notation::StandardForm if number.is_finite() && sf_range(number.clone().into()).is_ok() => GenericNumber::StandardForm(to_sf(&number)),
+        _ => GenericNumber::Decimal(number.into())
+    }
+}
+
+/// Applies an operation to the fractions of two numbers, failing if either of them has no exact fraction or the result overflows.
+#[cfg(not(feature="bigint"))]
+fn checked_exact_operation<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+    use fraction::{CheckedAdd,CheckedSub,CheckedMul,CheckedDiv};
+
+    let (Some(lhs),Some(rhs)) = (to_exact(lhs),to_exact(rhs)) else {
+        return Err(ArithmeticError::PrecisionLoss);
+    };
+
+    let result = match operation {
+        Operation::Add => lhs.checked_add(&rhs),
+        Operation::Sub => lhs.checked_sub(&rhs),
+        Operation::Mul => lhs.checked_mul(&rhs),
+        Operation::Div => lhs.checked_div(&rhs),
+        // truncates the quotient towards zero like `%` does
+        Operation::Rem => lhs.checked_div(&rhs)
+            .and_then(|quotient| rhs.checked_mul(&quotient.trunc()))
+            .and_then(|multiple| lhs.checked_sub(&multiple)),
+    };
+
+    result.map(GenericNumber::Fraction).ok_or(ArithmeticError::Overflow)
+}
+
+/// Applies an operation to the fractions of two numbers, failing if either of them has no exact fraction.
+#[cfg(feature="bigint")]
+fn checked_exact_operation<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+    exact_operation(operation,lhs,rhs).ok_or(ArithmeticError::PrecisionLoss)
+}
+
+/// Applies an operation in standard form, failing if the operands or the result are out of its range.
+fn checked_sf_operation<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Result<StandardForm,ArithmeticError> {
+    let float = operation.apply(lhs.clone().into(),rhs.clone().into());
+
+    for value in [lhs.clone().into(),rhs.clone().into(),float] {
+        sf_range(value)?;
+    }
+
+    let (lhs,rhs) = (to_sf(lhs),to_sf(rhs));
+    let (lhs_exponent,rhs_exponent) = (*lhs.exponent(),*rhs.exponent());
+
+    // the arithmetic of standard form combines the exponents of its operands, which must not overflow
+    let exponents_fit = match operation {
+        Operation::Add | Operation::Sub => lhs_exponent.abs_diff(rhs_exponent) <= i8::MAX as u8,
+        Operation::Mul => lhs_exponent.checked_add(rhs_exponent).is_some(),
+        Operation::Div => lhs_exponent.checked_sub(rhs_exponent).is_some(),
+        Operation::Rem => true
+    };
+
+    Ok(match (exponents_fit,operation) {
+        (false,_) => float.into(),
+        (true,Operation::Add) => lhs + rhs,
+        (true,Operation::Sub) => lhs - rhs,
+        (true,Operation::Mul) => lhs * rhs,
+        (true,Operation::Div) => lhs / rhs,
+        (true,Operation::Rem) => sf_rem(lhs,rhs),
+    })
+}
+
+/// Fails if a finite float is too large or too small to be held in standard form.
+pub(crate) fn sf_range(float : f64) -> Result<(),ArithmeticError> {
+    // standard form holds magnitudes from 10^-128 up to but excluding 10^128
+    match float.abs() {
+        magnitude if magnitude >= 1e128 => Err(ArithmeticError::Overflow),
+        magnitude if magnitude != 0.0 && magnitude < 1e-128 => Err(ArithmeticError::PrecisionLoss),
+        _ => Ok(())
+    }
+}
+
+/// Converts a finite number into standard form.
+pub(crate) fn to_sf<T : FractionInteger>(number : &GenericNumber<T>) -> StandardForm {
+    match number {
+        GenericNumber::Integer(i) => match i.is_negative() {
+            true => -ratio_to_sf(i.unsigned_abs(),1),
+            false => ratio_to_sf(i.unsigned_abs(),1)
+        },
+        GenericNumber::Decimal(d) => (*d).into(),
+        GenericNumber::StandardForm(sf) => sf.clone(),
+        GenericNumber::Fraction(fr) => fraction_to_sf(fr),
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(fr) => crate::bigint::big_fraction_to_sf(fr),
+        GenericNumber::Symbolic(symbolic) => symbolic.to_f64().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fraction::GenericFraction;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn half() -> Number {
+        Number::Fraction(GenericFraction::new(1u32,2u32))
+    }
+
+    fn third() -> Number {
+        Number::Fraction(GenericFraction::new(1u32,3u32))
+    }
+
+    fn is_fraction(number : &Number) -> bool {
+        matches!(number,Number::Fraction(_))
+    }
+
+    fn is_decimal(number : &Number) -> bool {
+        matches!(number,Number::Decimal(_))
+    }
+
+    fn is_standard_form(number : &Number) -> bool {
+        matches!(number,Number::StandardForm(_))
+    }
+
+    #[test]
+    fn test_prefer_exact() {
+        let result = Number::Decimal(0.25) + third();
+        assert!(is_fraction(&result));
+        assert_eq!(result,Number::Fraction(GenericFraction::new(7u32,12u32)));
+
+        let result = third() * Number::StandardForm(StandardForm::new(1.2,3));
+        assert!(is_fraction(&result));
+        assert_eq!(result,400);
+
+        // 1/3 as a float reads as 0.3333333333333333, which only a big fraction can hold
+        let result = third() + Number::Decimal(1.0 / 3.0);
+        #[cfg(not(feature="bigint"))]
+        assert!(is_decimal(&result));
+        #[cfg(feature="bigint")]
+        assert!(matches!(result,Number::BigFraction(_)));
+
+        assert!(is_standard_form(&(Number::Decimal(2.0) + Number::StandardForm(StandardForm::new(1.0,3)))));
+        assert!(is_decimal(&(Number::Decimal(2.0) + Number::Decimal(1.0))));
+    }
+
+    #[test]
+    fn test_prefer_left() {
+        let policy = PromotionPolicy::PreferLeft;
+
+        assert!(is_decimal(&Number::Decimal(0.25).add_with(&half(),policy)));
+        assert!(is_standard_form(&Number::StandardForm(StandardForm::new(1.0,3)).mul_with(&half(),policy)));
+        assert!(is_fraction(&half().sub_with(&Number::Decimal(0.25),policy)));
+        #[cfg(not(feature="bigint"))]
+        assert!(is_decimal(&half().div_with(&Number::Decimal(1.0 / 3.0),policy)));
+    }
+
+    #[test]
+    fn test_prefer_decimal() {
+        let result = half().add_with(&third(),PromotionPolicy::PreferDecimal);
+        assert!(is_decimal(&result));
+        assert_eq!(f64::from(result),0.5 + 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_prefer_standard_form() {
+        let policy = PromotionPolicy::PreferStandardForm;
+
+        let result = half().rem_with(&Number::Decimal(0.25),policy);
+        assert!(is_standard_form(&result));
+        assert_eq!(result,0);
+
+        // standard form cannot hold infinities
+        let result = half().div_with(&Number::Decimal(0.0),policy);
+        assert!(is_decimal(&result));
+        assert_eq!(result,f64::INFINITY);
+    }
+
+    #[test]
+    fn test_standard_form_out_of_range() {
+        let result = Number::Integer(i128::MAX) * Number::StandardForm(StandardForm::new(1.0,100));
+        #[cfg(not(feature="bigint"))]
+        assert!(is_decimal(&result));
+        assert!(result > 1.7e138 && result < 1.8e138);
+
+        let result = Number::StandardForm(StandardForm::new(5.0,100)) * Number::StandardForm(StandardForm::new(5.0,100));
+        #[cfg(not(feature="bigint"))]
+        assert!(is_decimal(&result));
+        assert!(result > 2.4e201 && result < 2.6e201);
+
+        let result = Number::Decimal(1e200) + Number::StandardForm(StandardForm::new(1.0,0));
+        #[cfg(not(feature="bigint"))]
+        assert!(is_decimal(&result));
+        assert_eq!(f64::from(result),1e200);
+
+        // the exponents of the operands are too far apart to be added in standard form
+        let result = Number::StandardForm(StandardForm::new(1.0,-100)) + Number::StandardForm(StandardForm::new(1.0,100));
+        assert!(is_standard_form(&result));
+        assert!(result > 0.9e100 && result < 1.1e100);
+    }
+
+    #[test]
+    fn test_integer_promotion() {
+        assert!(matches!(Number::Integer(2) + Number::Integer(3),Number::Integer(5)));
+        assert!(is_decimal(&(Number::Integer(2) + Number::Decimal(0.5))));
+        assert!(is_standard_form(&(Number::Integer(2) * Number::StandardForm(StandardForm::new(1.0,3)))));
+
+        let policy = PromotionPolicy::PreferLeft;
+        assert!(matches!(Number::Integer(2).add_with(&Number::Decimal(3.0),policy),Number::Integer(5)));
+        assert!(is_decimal(&Number::Integer(2).add_with(&Number::Decimal(0.5),policy)));
+        assert!(is_fraction(&Number::Integer(2).add_with(&half(),policy)));
+    }
+}
@@ -0,0 +1,209 @@
+use fraction::{GenericFraction,Integer,ToPrimitive};
+
+use crate::{GenericNumber,FractionInteger};
+use crate::number::to_u128_fraction;
+
+/// The combining overline drawn above each repeating digit, as in `0.3̅`.
+pub(crate) const OVERLINE : char = '\u{305}';
+
+/// The longest period `to_repeating_decimal` writes out, as the period of a fraction with denominator `n` can be up to `n - 1` digits long.
+pub(crate) const MAX_PERIOD_LENGTH : usize = 1000;
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Writes an integer or fraction as a decimal, putting the digits that repeat forever in parentheses,
+    /// so `1/6` is `0.1(6)`, `1/4` is `0.25` and `22/7` is `3.(142857)`.
+    ///
+    /// The repeating digits are found exactly by long division, so the period of a fraction with denominator `n`
+    /// can be up to `n - 1` digits long. Returns `None` for fractions whose period is longer than 1000 digits,
+    /// and for decimals, numbers in standard form, NaN and infinities.
+    pub fn to_repeating_decimal(&self) -> Option<String> {
+        let (negative,digits) = match self {
+            GenericNumber::Integer(i) => return Some(i.to_string()),
+            GenericNumber::Fraction(fr) => match to_u128_fraction(fr)? {
+                GenericFraction::Rational(sign,ratio) => (sign.is_negative(),repeating_digits(*ratio.numer(),*ratio.denom())?),
+                _ => return None
+            },
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(GenericFraction::Rational(sign,ratio)) => (sign.is_negative(),repeating_digits(ratio.numer().clone(),ratio.denom().clone())?),
+            _ => return None
+        };
+
+        let (whole,fixed,period) = digits;
+        let sign = match negative && (whole != "0" || !fixed.is_empty() || !period.is_empty()) {
+            true => "-",
+            false => ""
+        };
+
+        Some(match (fixed.is_empty(),period.is_empty()) {
+            (true,true) => format!("{sign}{whole}"),
+            (_,true) => format!("{sign}{whole}.{fixed}"),
+            _ => format!("{sign}{whole}.{fixed}({period})")
+        })
+    }
+}
+
+/// Divides `numer` by `denom`, returning the whole part, the decimals before the period and the repeating decimals.
+///
+/// The period starts once every factor of 2 and 5 in the denominator has been divided out, after which the remainders
+/// of the long division cycle back to the first remainder of the period. Returns `None` once the period grows longer than `MAX_PERIOD_LENGTH`.
+fn repeating_digits<N : Integer + Clone + From<u8> + ToPrimitive + std::fmt::Display>(numer : N,denom : N) -> Option<(String,String,String)> {
+    let (whole,mut remainder) = numer.div_rem(&denom);
+    let ten = N::from(10);
+
+    let mut reduced = denom.clone() / numer.gcd(&denom);
+    let mut fixed_length = [2,5].map(|factor| {
+        let (factor,mut count) = (N::from(factor),0);
+        while reduced.is_multiple_of(&factor) {
+            reduced = reduced.clone() / factor.clone();
+            count += 1;
+        }
+        count
+    }).into_iter().max().unwrap_or(0);
+
+    let next_digit = |remainder : &mut N| {
+        let (digit,rest) = (remainder.clone() * ten.clone()).div_rem(&denom);
+        *remainder = rest;
+        char::from_digit(digit.to_u32().expect("a decimal digit"),10).expect("a decimal digit")
+    };
+
+    let mut fixed = String::new();
+    while fixed_length > 0 && !remainder.is_zero() {
+        fixed.push(next_digit(&mut remainder));
+        fixed_length -= 1;
+    }
+
+    let mut period = String::new();
+    let start = remainder.clone();
+    while !remainder.is_zero() {
+        if period.len() == MAX_PERIOD_LENGTH {
+            return None;
+        }
+        period.push(next_digit(&mut remainder));
+        if remainder == start {
+            break;
+        }
+    }
+
+    Some((whole.to_string(),fixed,period))
+}
+
+/// Finds the repeating part of decimals written before `...`, which is the shortest group of digits
+/// that ends them and is repeated at least twice, so `1666` gives the period `6` and `1212` gives `12`.
+pub(crate) fn split_ellipsis(decimals : &str) -> Option<(&str,&str)> {
+    if !decimals.is_ascii() {
+        return None;
+    }
+
+    (1..=decimals.len() / 2).find_map(|length| {
+        let (rest,period) = decimals.split_at(decimals.len() - length);
+        rest.ends_with(period).then_some((rest,period))
+    })
+}
+
+/// Builds the exact value of a repeating decimal from its whole part, the decimals before the period and the period.
+///
+/// Returns `None` if a part is not made of ASCII digits, the period is empty, or the digits do not fit into an `i128`.
+pub(crate) fn from_repeating_parts<T : FractionInteger>(negative : bool,whole : &str,fixed : &str,period : &str) -> Option<GenericNumber<T>> {
+    let is_digits = |digits : &str| digits.bytes().all(|byte| byte.is_ascii_digit());
+    if period.is_empty() || !is_digits(whole) || !is_digits(fixed) || !is_digits(period) {
+        return None;
+    }
+
+    // `x.ab(cd)` is `(xabcd - xab) / (10^2 * (10^2 - 1))`
+    let parse = |digits : String| match digits.is_empty() {
+        true => Some(0),
+        false => digits.parse::<i128>().ok()
+    };
+    let numer = parse(format!("{whole}{fixed}{period}"))?.checked_sub(parse(format!("{whole}{fixed}"))?)?;
+    let power = |length : usize| 10_i128.checked_pow(u32::try_from(length).ok()?);
+    let denom = power(fixed.len())?.checked_mul(power(period.len())? - 1)?;
+
+    // reducing first keeps fractions whose unreduced parts are too large for `T` exact
+    let common = numer.gcd(&denom);
+    let value = GenericNumber::Integer(numer / common) / GenericNumber::Integer(denom / common);
+    Some(match negative {
+        true => -value,
+        false => value
+    })
+}
+
+/// Parses a repeating decimal written as `0.1(6)`, `0.1666...` or with an overline above each repeating digit as in `0.16̅`.
+pub(crate) fn parse_repeating<T : FractionInteger>(value : &str) -> Option<GenericNumber<T>> {
+    let (negative,rest) = match value.strip_prefix('-') {
+        Some(rest) => (true,rest),
+        None => (false,value.strip_prefix('+').unwrap_or(value))
+    };
+    let (whole,decimals) = rest.split_once('.')?;
+
+    let (fixed,period) = if let Some(decimals) = decimals.strip_suffix(')') {
+        decimals.split_once('(')?
+    } else if let Some(decimals) = decimals.strip_suffix("...").or_else(|| decimals.strip_suffix('…')) {
+        split_ellipsis(decimals)?
+    } else {
+        // every digit after the first overlined one must be overlined too
+        let start = decimals.find(OVERLINE)?.checked_sub(1).filter(|&start| decimals.is_char_boundary(start))?;
+        let (fixed,overlined) = decimals.split_at(start);
+        let period : String = overlined.chars().step_by(2).collect();
+        if overlined.chars().skip(1).step_by(2).any(|c| c != OVERLINE) || overlined.chars().count() != period.len() * 2 {
+            return None;
+        }
+        return from_repeating_parts(negative,whole,fixed,&period);
+    };
+
+    from_repeating_parts(negative,whole,fixed,period)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Number;
+
+    use super::*;
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_parsing() {
+        assert!(Number::try_from("0.1(6)").unwrap().structural_eq(&fraction(1,6)));
+        assert!(Number::try_from("0.(3)").unwrap().structural_eq(&fraction(1,3)));
+        assert!(Number::try_from("3.(142857)").unwrap().structural_eq(&fraction(22,7)));
+        assert!(Number::try_from("-1.(3)").unwrap().structural_eq(&-fraction(4,3)));
+        assert!(Number::try_from("0.(9)").unwrap().structural_eq(&Number::Integer(1)));
+
+        assert!(Number::try_from("0.1666...").unwrap().structural_eq(&fraction(1,6)));
+        assert!(Number::try_from("0.121212…").unwrap().structural_eq(&fraction(4,33)));
+        assert!(Number::try_from("0.123...").is_err());
+
+        assert!(Number::try_from("0.3\u{305}").unwrap().structural_eq(&fraction(1,3)));
+        assert!(Number::try_from("0.16\u{305}").unwrap().structural_eq(&fraction(1,6)));
+        assert!(Number::try_from("0.1\u{305}2\u{305}").unwrap().structural_eq(&fraction(4,33)));
+        assert!(Number::try_from("0.1\u{305}2").is_err());
+
+        assert!(Number::try_from("0.1()").is_err());
+        assert!(Number::try_from("0.1(x)").is_err());
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(fraction(1,6).to_repeating_decimal().unwrap(),"0.1(6)");
+        assert_eq!(fraction(1,4).to_repeating_decimal().unwrap(),"0.25");
+        assert_eq!(fraction(22,7).to_repeating_decimal().unwrap(),"3.(142857)");
+        assert_eq!(fraction(1,12).to_repeating_decimal().unwrap(),"0.08(3)");
+        assert_eq!((-fraction(4,3)).to_repeating_decimal().unwrap(),"-1.(3)");
+        assert_eq!(fraction(6,3).to_repeating_decimal().unwrap(),"2");
+        assert_eq!(Number::Integer(-7).to_repeating_decimal().unwrap(),"-7");
+        assert_eq!(Number::Decimal(0.5).to_repeating_decimal(),None);
+
+        let seventeenth = fraction(1,17).to_repeating_decimal().unwrap();
+        assert_eq!(seventeenth,"0.(0588235294117647)");
+        assert!(Number::try_from(seventeenth.as_str()).unwrap().structural_eq(&fraction(1,17)));
+    }
+
+    #[test]
+    fn test_long_periods() {
+        assert_eq!(fraction(1,983).to_repeating_decimal().map(|decimal| decimal.len()),Some("0.()".len() + 982));
+        assert_eq!(fraction(1,1019).to_repeating_decimal(),None);
+        assert_eq!(fraction(1,4_294_967_291).to_repeating_decimal(),None);
+    }
+}
@@ -0,0 +1,410 @@
+use std::ops::{Mul,Div,Neg};
+
+use fraction::{GenericFraction,Integer};
+
+#[cfg(feature="bigint")]
+use fraction::{ToPrimitive,Zero};
+
+use crate::{GenericNumber,FractionInteger,ArithmeticError};
+use crate::number::{float_to_sf,signed_integer,to_u128_fraction};
+
+/// The largest factor that is divided out of a radicand when simplifying a surd.
+///
+/// Every radicand below `2^60` is fully simplified, as what remains after dividing out the factors up to its cube root
+/// has at most two prime factors.
+const TRIAL_DIVISOR_LIMIT : u128 = 1 << 20;
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Computes the square root of the number.
+    ///
+    /// The square roots of integers and fractions that are perfect squares are exact, as in `sqrt(4/9) = 2/3`.
+    /// Other roots are decimals, while numbers in standard form stay in standard form. See `Surd` for exact
+    /// square roots of every integer and fraction.
+    pub fn sqrt(&self) -> GenericNumber<T> {
+        self.nth_root(2)
+    }
+
+    /// Computes the cube root of the number, which is exact for perfect cubes like `sqrt`.
+    pub fn cbrt(&self) -> GenericNumber<T> {
+        self.nth_root(3)
+    }
+
+    /// Computes the `degree`th root of the number, which is exact for perfect powers like `sqrt`.
+    ///
+    /// Negative numbers have a root of odd degree, while their roots of even degree are NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is zero.
+    pub fn nth_root(&self,degree : u32) -> GenericNumber<T> {
+        assert!(degree > 0,"the degree of a root must be at least one");
+
+        if let Some(root) = exact_root(self,degree) {
+            return root;
+        }
+
+        match self {
+            GenericNumber::StandardForm(sf) => GenericNumber::StandardForm(float_to_sf(float_root(f64::from(sf.clone()),degree))),
+            number => GenericNumber::Decimal(float_root(f64::from(number.clone()),degree))
+        }
+    }
+}
+
+/// Takes the `degree`th root of an integer or fraction exactly, returning `None` if it is not a perfect power
+/// or if it is negative and `degree` is even.
+pub(crate) fn exact_root<T : FractionInteger>(number : &GenericNumber<T>,degree : u32) -> Option<GenericNumber<T>> {
+    match number {
+        GenericNumber::Integer(i) if i.is_negative() && degree.is_multiple_of(2) => None,
+        GenericNumber::Integer(i) => signed_integer(i.is_negative(),integer_root(i.unsigned_abs(),degree)?).map(GenericNumber::Integer),
+        GenericNumber::Fraction(_) => {
+            let (negative,numer,denom) = rational_parts(number)?;
+            if negative && degree.is_multiple_of(2) {
+                return None;
+            }
+
+            let (numer,denom) = (T::from_u128(integer_root(numer,degree)?)?,T::from_u128(integer_root(denom,degree)?)?);
+            Some(GenericNumber::Fraction(match negative {
+                true => GenericFraction::new_neg(numer,denom),
+                false => GenericFraction::new(numer,denom)
+            }))
+        },
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(fr) => crate::bigint::big_fraction_root(fr,degree).map(crate::bigint::narrow),
+        _ => None
+    }
+}
+
+/// Returns the sign, numerator and denominator of an integer or fraction, or `None` if they do not fit into a `u128`.
+fn rational_parts<T : FractionInteger>(number : &GenericNumber<T>) -> Option<(bool,u128,u128)> {
+    match number {
+        GenericNumber::Integer(i) => Some((i.is_negative(),i.unsigned_abs(),1)),
+        GenericNumber::Fraction(fr) => match to_u128_fraction(fr)? {
+            GenericFraction::Rational(sign,ratio) => Some((sign.is_negative() && *ratio.numer() != 0,*ratio.numer(),*ratio.denom())),
+            _ => None
+        },
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(GenericFraction::Rational(sign,ratio)) => {
+            Some((sign.is_negative() && !ratio.is_zero(),ratio.numer().to_u128()?,ratio.denom().to_u128()?))
+        },
+        _ => None
+    }
+}
+
+/// Returns the `degree`th root of `value` if it is a perfect power.
+fn integer_root(value : u128,degree : u32) -> Option<u128> {
+    if degree == 1 || value < 2 {
+        return Some(value);
+    }
+
+    // Newton's method falls towards the root from an estimate above it
+    let bits = u128::BITS - value.leading_zeros();
+    let mut root = 1_u128 << bits.div_ceil(degree);
+    loop {
+        let quotient = root.checked_pow(degree - 1).map_or(0,|power| value / power);
+        let next = ((degree as u128 - 1) * root + quotient) / degree as u128;
+        if next >= root {
+            break;
+        }
+        root = next;
+    }
+
+    (root.checked_pow(degree) == Some(value)).then_some(root)
+}
+
+/// Takes the `degree`th root of a float, keeping the sign of negative numbers for roots of odd degree.
+fn float_root(float : f64,degree : u32) -> f64 {
+    match degree {
+        2 => float.sqrt(),
+        3 => float.cbrt(),
+        _ if float < 0.0 && degree % 2 == 1 => -(-float).powf(1.0 / degree as f64),
+        _ => float.powf(1.0 / degree as f64)
+    }
+}
+
+/// Splits `value` into `square^2 * free`, where `free` has no square factors up to `TRIAL_DIVISOR_LIMIT`.
+fn square_free(value : u128) -> (u128,u128) {
+    let (mut square,mut free,mut rest) = (1,1,value);
+
+    let mut divisor = 2_u128;
+    while divisor <= TRIAL_DIVISOR_LIMIT && divisor.pow(3) <= rest {
+        let mut count = 0;
+        while rest % divisor == 0 {
+            rest /= divisor;
+            count += 1;
+        }
+
+        square *= divisor.pow(count / 2);
+        if count % 2 == 1 {
+            free *= divisor;
+        }
+        divisor += 1;
+    }
+
+    let root = rest.isqrt();
+    match root * root == rest {
+        true => square *= root,
+        false => free *= rest
+    }
+
+    (square,free)
+}
+
+/// An exact square root of a rational number, written as `coefficient * √radicand / denominator`.
+///
+/// Surds are kept simplified: square factors are taken out of the radicand and the coefficient and denominator
+/// share no common factor, so `√12` is `2√3` and `1/√3` is `√3/3`. Radicands with two repeated prime factors
+/// above `2^20` may keep a square factor, which leaves the value unchanged.
+///
+/// Multiplying or dividing two surds gives another surd. The operators panic if the result overflows or on
+/// division by zero, while `checked_mul` and `checked_div` return an `ArithmeticError` instead.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub struct Surd {
+    coefficient : i128,
+    radicand : u128,
+    denominator : u128
+}
+
+impl Surd {
+    /// Creates the surd `coefficient * √radicand / denominator`, simplifying it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero or if the simplified coefficient overflows.
+    pub fn new(coefficient : i128,radicand : u128,denominator : u128) -> Self {
+        assert!(denominator != 0,"the denominator of a surd must not be zero");
+        Self::simplified(coefficient,radicand,denominator).expect("the coefficient of the simplified surd overflowed")
+    }
+
+    /// Takes the exact square root of an integer or fraction.
+    ///
+    /// Returns `None` if the number is negative, is neither an integer nor a fraction, or if its square root does not fit.
+    pub fn sqrt<T : FractionInteger>(number : &GenericNumber<T>) -> Option<Self> {
+        let (negative,numer,denom) = rational_parts(number)?;
+        if negative {
+            return None;
+        }
+
+        // √(p/q) = √(pq)/q, which is found without multiplying p and q by simplifying their square roots separately
+        let ((numer_square,numer_free),(denom_square,denom_free)) = (square_free(numer),square_free(denom));
+        let coefficient = i128::try_from(numer_square).ok()?;
+        Some(Self::reduced(coefficient,numer_free.checked_mul(denom_free)?,denom_square.checked_mul(denom_free)?))
+    }
+
+    /// Retrieves the coefficient, which carries the sign of the surd.
+    pub fn coefficient(&self) -> i128 {
+        self.coefficient
+    }
+
+    /// Retrieves the radicand, which has no square factors.
+    pub fn radicand(&self) -> u128 {
+        self.radicand
+    }
+
+    /// Retrieves the denominator.
+    pub fn denominator(&self) -> u128 {
+        self.denominator
+    }
+
+    /// Returns `true` if the surd is rational, which is when its radicand is one.
+    pub fn is_rational(&self) -> bool {
+        self.radicand == 1
+    }
+
+    /// Multiplies two surds, failing with `ArithmeticError::Overflow` if the result does not fit.
+    pub fn checked_mul(&self,other : &Self) -> Result<Self,ArithmeticError> {
+        // √a√b = g√(a/g * b/g) for g = gcd(a,b), which has no square factors as a and b have none
+        let common = self.radicand.gcd(&other.radicand);
+        let radicand = (self.radicand / common).checked_mul(other.radicand / common).ok_or(ArithmeticError::Overflow)?;
+
+        // cancelling across the product first keeps its parts small
+        let (lhs,rhs) = (cancel(self.coefficient,other.denominator),cancel(other.coefficient,self.denominator));
+        let coefficient = i128::try_from(common).ok()
+            .and_then(|common| lhs.0.checked_mul(rhs.0)?.checked_mul(common))
+            .ok_or(ArithmeticError::Overflow)?;
+        let denominator = lhs.1.checked_mul(rhs.1).ok_or(ArithmeticError::Overflow)?;
+
+        Ok(Self::reduced(coefficient,radicand,denominator))
+    }
+
+    /// Divides the surd by `other`, failing with `ArithmeticError::DivisionByZero` if `other` is zero
+    /// or `ArithmeticError::Overflow` if the result does not fit.
+    pub fn checked_div(&self,other : &Self) -> Result<Self,ArithmeticError> {
+        if other.coefficient == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        // c/(a√b) = c√b/(ab)
+        let coefficient = i128::try_from(other.denominator).map_err(|_| ArithmeticError::Overflow)?;
+        let denominator = other.coefficient.unsigned_abs().checked_mul(other.radicand).ok_or(ArithmeticError::Overflow)?;
+        let reciprocal = Self::reduced(coefficient * other.coefficient.signum(),other.radicand,denominator);
+
+        self.checked_mul(&reciprocal)
+    }
+
+    /// Takes the square factors out of the radicand before reducing the surd.
+    fn simplified(coefficient : i128,radicand : u128,denominator : u128) -> Result<Self,ArithmeticError> {
+        let (square,free) = square_free(radicand);
+        let coefficient = i128::try_from(square).ok()
+            .and_then(|square| coefficient.checked_mul(square))
+            .ok_or(ArithmeticError::Overflow)?;
+
+        Ok(Self::reduced(coefficient,free,denominator))
+    }
+
+    /// Divides the coefficient and denominator of a surd, whose radicand has no square factors, by their greatest common divisor.
+    fn reduced(coefficient : i128,radicand : u128,denominator : u128) -> Self {
+        if coefficient == 0 || radicand == 0 {
+            return Self { coefficient : 0 , radicand : 1 , denominator : 1 };
+        }
+
+        let (coefficient,denominator) = cancel(coefficient,denominator);
+        Self { coefficient , radicand , denominator }
+    }
+}
+
+/// Divides an integer and a denominator by their greatest common divisor.
+fn cancel(integer : i128,denominator : u128) -> (i128,u128) {
+    let common = integer.unsigned_abs().gcd(&denominator);
+    match common {
+        0 | 1 => (integer,denominator),
+        // the divisor is at least two, so the quotient fits into an `i128`
+        common => ((integer.unsigned_abs() / common) as i128 * integer.signum(),denominator / common)
+    }
+}
+
+impl std::fmt::Display for Surd {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.coefficient < 0 {
+            write!(f,"-")?;
+        }
+
+        match (self.coefficient.unsigned_abs(),self.radicand) {
+            (coefficient,1) => write!(f,"{coefficient}")?,
+            (1,radicand) => write!(f,"√{radicand}")?,
+            (coefficient,radicand) => write!(f,"{coefficient}√{radicand}")?
+        }
+
+        match self.denominator {
+            1 => Ok(()),
+            denominator => write!(f,"/{denominator}")
+        }
+    }
+}
+
+impl From<Surd> for f64 {
+    fn from(value: Surd) -> Self {
+        value.coefficient as f64 * (value.radicand as f64).sqrt() / value.denominator as f64
+    }
+}
+
+/// Rational surds become integers or fractions, while irrational surds become the nearest decimal.
+impl<T : FractionInteger> From<Surd> for GenericNumber<T> {
+    fn from(value: Surd) -> Self {
+        let fraction = match (value.radicand,value.denominator) {
+            (1,1) => return GenericNumber::Integer(value.coefficient),
+            (1,denominator) => T::from_u128(value.coefficient.unsigned_abs()).zip(T::from_u128(denominator)),
+            _ => None
+        };
+
+        match fraction {
+            Some((numer,denom)) if value.coefficient < 0 => GenericNumber::Fraction(GenericFraction::new_neg(numer,denom)),
+            Some((numer,denom)) => GenericNumber::Fraction(GenericFraction::new(numer,denom)),
+            None => GenericNumber::Decimal(value.into())
+        }
+    }
+}
+
+impl Neg for Surd {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { coefficient : self.coefficient.checked_neg().expect("the negated surd overflowed") , ..self }
+    }
+}
+
+impl Mul for Surd {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(&other).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl Div for Surd {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.checked_div(&other).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_exact_roots() {
+        assert!(fraction(4,9).sqrt().structural_eq(&fraction(2,3)));
+        assert!(Number::Integer(16).sqrt().structural_eq(&Number::Integer(4)));
+        assert!(Number::Integer(-27).cbrt().structural_eq(&Number::Integer(-3)));
+        assert!(fraction(81,16).nth_root(4).structural_eq(&fraction(3,2)));
+        assert!(Number::Integer(i128::MAX).nth_root(1).structural_eq(&Number::Integer(i128::MAX)));
+        assert!(Number::Integer(1 << 120).nth_root(8).structural_eq(&Number::Integer(1 << 15)));
+    }
+
+    #[test]
+    fn test_inexact_roots() {
+        assert!(Number::Integer(2).sqrt().structural_eq(&Number::Decimal(2_f64.sqrt())));
+        assert!(Number::Integer(-4).sqrt().is_nan());
+        assert!(Number::Decimal(-32.0).nth_root(5).structural_eq(&Number::Decimal(-2.0)));
+
+        let root = Number::StandardForm(StandardForm::new(1.0,100)).sqrt();
+        assert!(matches!(root,Number::StandardForm(_)));
+        assert_eq!(f64::from(root),1e50);
+    }
+
+    #[test]
+    fn test_surds() {
+        let surd = Surd::sqrt(&fraction(4,3)).unwrap();
+        assert_eq!(surd,Surd::new(2,3,3));
+        assert_eq!(surd.to_string(),"2√3/3");
+
+        assert_eq!(Surd::new(1,12,1).to_string(),"2√3");
+        assert_eq!(Surd::sqrt(&fraction(1,2)).unwrap().to_string(),"√2/2");
+        assert_eq!(Surd::sqrt(&fraction(9,4)).unwrap().to_string(),"3/2");
+        assert_eq!((-Surd::new(1,3,1)).to_string(),"-√3");
+        assert_eq!(Surd::sqrt(&Number::Integer(-1)),None);
+        assert_eq!(Surd::sqrt(&Number::Decimal(2.0)),None);
+    }
+
+    #[test]
+    fn test_surd_arithmetic() {
+        let (two,six) = (Surd::new(1,2,1),Surd::new(1,6,1));
+        assert_eq!(two * six,Surd::new(2,3,1));
+        assert_eq!(two * two,Surd::new(2,1,1));
+        assert_eq!(Surd::new(1,1,1) / Surd::new(1,3,1),Surd::new(1,3,3));
+        assert_eq!(six / two,Surd::new(1,3,1));
+        assert_eq!(two.checked_div(&Surd::new(0,5,1)),Err(ArithmeticError::DivisionByZero));
+
+        assert!(Number::from(two * two).structural_eq(&Number::Integer(2)));
+        assert!(Number::from(Surd::new(-3,1,4)).structural_eq(&Number::Fraction(GenericFraction::new_neg(3u32,4u32))));
+        assert_eq!(f64::from(Surd::new(2,3,3)),2.0 * 3_f64.sqrt() / 3.0);
+    }
+
+    #[cfg(feature="bigint")]
+    #[test]
+    fn test_big_roots() {
+        let big = Number::Integer(10_i128.pow(19)) * Number::Fraction(GenericFraction::new(10_u32.pow(9),1u32));
+        let root = Number::try_from("1/100").unwrap().sqrt() * big.sqrt();
+        assert!(matches!(big,Number::BigFraction(_)));
+        assert_eq!(root,Number::Integer(10_i128.pow(13)));
+    }
+}
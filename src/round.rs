@@ -0,0 +1,513 @@
+use std::cmp::Ordering;
+
+use fraction::{GenericFraction, Ratio};
+use standardform::StandardForm;
+
+use crate::{GenericNumber,FractionInteger};
+use crate::number::{digits_to_sf, fraction_to_f64, sf_decimal, signed_integer, times_ten_div_rem, to_u128_fraction};
+
+/// Decides which way a number is rounded when it lies between two values of the requested precision.
+///
+/// The modes rounding to the nearest value only differ for ties, which lie exactly halfway between the two.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,Default)]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, with ties going to the value whose last digit is even.
+    #[default]
+    HalfEven,
+
+    /// Rounds to the nearest value, with ties going away from zero.
+    HalfUp,
+
+    /// Rounds to the nearest value, with ties going towards zero.
+    HalfDown,
+
+    /// Rounds towards zero, dropping the digits beyond the precision.
+    TowardZero,
+
+    /// Rounds away from zero.
+    AwayFromZero,
+
+    /// Rounds towards negative infinity.
+    Floor,
+
+    /// Rounds towards positive infinity.
+    Ceiling,
+}
+
+/// How the digits dropped by rounding compare to half of a unit in the last digit kept, given that they are not all zero.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub(crate) enum Dropped {
+    BelowHalf,
+    Half,
+    AboveHalf
+}
+
+impl Dropped {
+    /// Reads how a non-zero remainder compares to the rest of its divisor, which is the same as comparing it to half the divisor.
+    pub(crate) fn from_cmp(remainder_to_rest : Ordering) -> Self {
+        match remainder_to_rest {
+            Ordering::Less => Dropped::BelowHalf,
+            Ordering::Equal => Dropped::Half,
+            Ordering::Greater => Dropped::AboveHalf
+        }
+    }
+
+    /// Compares a remainder to half of its divisor, returning `None` if nothing remains.
+    fn of(remainder : u128,divisor : u128) -> Option<Self> {
+        (remainder != 0).then(|| Dropped::from_cmp(remainder.cmp(&(divisor - remainder))))
+    }
+}
+
+impl RoundingMode {
+    /// Returns `true` if the magnitude of a number is rounded away from zero, given whether
+    /// the last digit kept is odd and how the digits that are dropped compare to half.
+    pub(crate) fn rounds_away(self,negative : bool,odd : bool,dropped : Dropped) -> bool {
+        match (self,dropped) {
+            (RoundingMode::HalfEven,Dropped::Half) => odd,
+            (RoundingMode::HalfUp,Dropped::Half) => true,
+            (RoundingMode::HalfDown,Dropped::Half) => false,
+            (RoundingMode::HalfEven | RoundingMode::HalfUp | RoundingMode::HalfDown,_) => dropped == Dropped::AboveHalf,
+            (RoundingMode::TowardZero,_) => false,
+            (RoundingMode::AwayFromZero,_) => true,
+            (RoundingMode::Floor,_) => negative,
+            (RoundingMode::Ceiling,_) => !negative,
+        }
+    }
+
+    /// Rounds the digits kept up by one if they are rounded away from zero, returning `None` if that overflows.
+    fn apply(self,negative : bool,kept : u128,dropped : Dropped) -> Option<u128> {
+        match self.rounds_away(negative,kept % 2 == 1,dropped) {
+            true => kept.checked_add(1),
+            false => Some(kept)
+        }
+    }
+}
+
+/// The magnitude of a rounded number, counted in units of the last decimal place kept.
+enum Rounded {
+    /// Rounding leaves the number as it is, because it has no digits beyond the precision.
+    Unchanged,
+    Kept(u128)
+}
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Returns the largest integer less than or equal to the number, keeping its variant.
+    pub fn floor(&self) -> GenericNumber<T> {
+        self.round_to(0,RoundingMode::Floor)
+    }
+
+    /// Returns the smallest integer greater than or equal to the number, keeping its variant.
+    pub fn ceil(&self) -> GenericNumber<T> {
+        self.round_to(0,RoundingMode::Ceiling)
+    }
+
+    /// Returns the nearest integer to the number, keeping its variant. Ties round away from zero like `f64::round`.
+    pub fn round(&self) -> GenericNumber<T> {
+        self.round_to(0,RoundingMode::HalfUp)
+    }
+
+    /// Returns the integer part of the number, keeping its variant.
+    pub fn trunc(&self) -> GenericNumber<T> {
+        self.round_to(0,RoundingMode::TowardZero)
+    }
+
+    /// Returns the fractional part of the number, which has the same sign as the number.
+    ///
+    /// Like `f64::fract`, the fractional part of an infinity is NaN.
+    pub fn fract(&self) -> GenericNumber<T> {
+        match self {
+            GenericNumber::Integer(_) => GenericNumber::Integer(0),
+            GenericNumber::Decimal(d) => GenericNumber::Decimal(d.fract()),
+            GenericNumber::StandardForm(sf) if !sf.mantissa().is_finite() => GenericNumber::Decimal(sf.mantissa().fract()),
+            GenericNumber::StandardForm(sf) => GenericNumber::StandardForm(sf_fract(sf)),
+            GenericNumber::Fraction(fr) => GenericNumber::Fraction(fr.fract()),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => GenericNumber::BigFraction(fr.fract()),
+            GenericNumber::Symbolic(symbolic) => GenericNumber::Decimal(symbolic.to_f64().fract()),
+        }
+    }
+
+    /// Rounds the number to `decimal_places` digits after the decimal point, keeping its variant.
+    ///
+    /// A negative number of decimal places rounds to tens, hundreds and so on. Fractions and integers are
+    /// rounded exactly, while decimals and numbers in standard form are rounded as the decimals they are written as,
+    /// so `2.675` rounds to `2.68` even though the nearest `f64` is slightly below it. NaN and infinities are left as they are.
+    ///
+    /// A fraction whose rounded value does not fit into `T` is a big fraction with the `bigint` feature,
+    /// and the nearest decimal otherwise. Multiples of `π` and `e` are evaluated into a decimal before rounding.
+    pub fn round_to(&self,decimal_places : i32,mode : RoundingMode) -> GenericNumber<T> {
+        match self {
+            GenericNumber::Integer(i) => match round_decimal(i.is_negative(),i.unsigned_abs(),0,decimal_places,mode) {
+                Rounded::Unchanged => self.clone(),
+                Rounded::Kept(kept) => integer_result(i.is_negative(),kept,decimal_places)
+            },
+            GenericNumber::Decimal(d) if !d.is_finite() || *d == 0.0 => self.clone(),
+            GenericNumber::Decimal(d) => {
+                let (significand,power) = float_decimal(*d);
+                match round_decimal(d.is_sign_negative(),significand,power,decimal_places,mode) {
+                    Rounded::Unchanged => self.clone(),
+                    Rounded::Kept(kept) => GenericNumber::Decimal(float_result(d.is_sign_negative(),kept,decimal_places))
+                }
+            },
+            GenericNumber::StandardForm(sf) if !sf.mantissa().is_finite() || *sf.mantissa() == 0.0 => self.clone(),
+            GenericNumber::StandardForm(sf) => {
+                let negative = sf.mantissa().is_sign_negative();
+                let (significand,power) = sf_decimal(sf);
+                match round_decimal(negative,significand,power,decimal_places,mode) {
+                    Rounded::Unchanged => self.clone(),
+                    Rounded::Kept(kept) => sf_result(negative,kept,decimal_places)
+                }
+            },
+            GenericNumber::Fraction(fr) => match to_u128_fraction(fr) {
+                Some(GenericFraction::Rational(sign,ratio)) => match round_ratio(sign.is_negative(),*ratio.numer(),*ratio.denom(),decimal_places,mode) {
+                    Some(Rounded::Unchanged) => self.clone(),
+                    Some(Rounded::Kept(kept)) => fraction_result(sign.is_negative(),kept,decimal_places),
+                    None => round_wide_fraction(fr,decimal_places,mode)
+                },
+                Some(_) => self.clone(),
+                None => round_wide_fraction(fr,decimal_places,mode)
+            },
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => crate::bigint::narrow(crate::bigint::round_big_fraction(fr,decimal_places,mode)),
+            GenericNumber::Symbolic(symbolic) => GenericNumber::Decimal(symbolic.to_f64()).round_to(decimal_places,mode),
+        }
+    }
+
+    /// Rounds the number to `figures` significant figures, keeping its variant like `round_to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `figures` is zero.
+    pub fn round_to_significant_figures(&self,figures : u32,mode : RoundingMode) -> GenericNumber<T> {
+        assert!(figures > 0,"a number has at least one significant figure");
+
+        match leading_exponent(self) {
+            Some(exponent) => {
+                let figures = i32::try_from(figures).unwrap_or(i32::MAX);
+                self.round_to(figures.saturating_sub(1).saturating_sub(exponent),mode)
+            },
+            None => self.clone()
+        }
+    }
+}
+
+/// Returns the exponent of the first significant digit of a number, or `None` if it is zero, NaN or an infinity.
+pub(crate) fn leading_exponent<T : FractionInteger>(number : &GenericNumber<T>) -> Option<i32> {
+    match number {
+        GenericNumber::Integer(0) => None,
+        GenericNumber::Integer(i) => Some(i.unsigned_abs().ilog10() as i32),
+        GenericNumber::Decimal(d) if !d.is_finite() || *d == 0.0 => None,
+        GenericNumber::Decimal(d) => {
+            let (significand,power) = float_decimal(*d);
+            Some(significand.ilog10() as i32 + power)
+        },
+        GenericNumber::StandardForm(sf) if !sf.mantissa().is_finite() || *sf.mantissa() == 0.0 => None,
+        GenericNumber::StandardForm(sf) => Some(*sf.exponent() as i32),
+        GenericNumber::Fraction(fr) => match to_u128_fraction(fr) {
+            Some(GenericFraction::Rational(_,ratio)) if *ratio.numer() != 0 => Some(ratio_exponent(*ratio.numer(),*ratio.denom())),
+            Some(_) => None,
+            None => leading_exponent::<T>(&GenericNumber::Decimal(fraction_to_f64(fr)))
+        },
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(fr) => crate::bigint::big_fraction_exponent(fr),
+        GenericNumber::Symbolic(symbolic) => leading_exponent::<T>(&GenericNumber::Decimal(symbolic.to_f64())),
+    }
+}
+
+/// Returns the exponent of the first significant digit of `numer / denom`, given that it is positive.
+fn ratio_exponent(numer : u128,denom : u128) -> i32 {
+    let whole = numer / denom;
+    if whole != 0 {
+        return whole.ilog10() as i32;
+    }
+
+    let (mut exponent,mut remainder) = (-1,numer % denom);
+    loop {
+        let (digit,next) = times_ten_div_rem(remainder,denom);
+        if digit != 0 {
+            return exponent;
+        }
+        exponent -= 1;
+        remainder = next;
+    }
+}
+
+/// Splits the magnitude of a finite, non-zero float into the decimal it is written as, `significand * 10^power`.
+fn float_decimal(float : f64) -> (u128,i32) {
+    // the shortest scientific notation round trips, so its digits are the intended value
+    let scientific = format!("{:e}",float.abs());
+    let (mantissa,exponent) = scientific.split_once('e').expect("floats format with an exponent");
+    let (whole,decimals) = mantissa.split_once('.').unwrap_or((mantissa,""));
+    let significand = format!("{whole}{decimals}").parse().expect("digits of a finite float");
+    let exponent : i32 = exponent.parse().expect("a valid exponent");
+    (significand,exponent - decimals.len() as i32)
+}
+
+/// Rounds the magnitude `significand * 10^power` to `places` decimal places.
+fn round_decimal(negative : bool,significand : u128,power : i32,places : i32,mode : RoundingMode) -> Rounded {
+    let dropped_digits = -(places as i64) - power as i64;
+    if dropped_digits <= 0 {
+        return Rounded::Unchanged;
+    }
+
+    let (kept,dropped) = match u32::try_from(dropped_digits).ok().and_then(|digits| 10_u128.checked_pow(digits)) {
+        Some(scale) => (significand / scale,Dropped::of(significand % scale,scale)),
+        // every significand is below 2^128, which is less than half of 10^39
+        None => (0,Some(Dropped::BelowHalf))
+    };
+
+    match dropped {
+        Some(dropped) => Rounded::Kept(mode.apply(negative,kept,dropped).expect("dropping a digit leaves room to round up")),
+        None => Rounded::Unchanged
+    }
+}
+
+/// Rounds the magnitude `numer / denom` to `places` decimal places, returning `None` if the digits kept do not fit into a `u128`.
+fn round_ratio(negative : bool,numer : u128,denom : u128,places : i32,mode : RoundingMode) -> Option<Rounded> {
+    let (whole,remainder) = (numer / denom,numer % denom);
+
+    let (kept,dropped) = match places >= 0 {
+        true => {
+            let (mut kept,mut remainder) = (whole,remainder);
+            // long division, as multiplying the numerator by a power of ten could overflow
+            for _ in 0..places {
+                if remainder == 0 {
+                    return Some(Rounded::Unchanged);
+                }
+
+                let (digit,next) = times_ten_div_rem(remainder,denom);
+                kept = kept.checked_mul(10)?.checked_add(digit as u128)?;
+                remainder = next;
+            }
+
+            (kept,Dropped::of(remainder,denom))
+        },
+        false => match 10_u128.checked_pow(places.unsigned_abs()) {
+            Some(scale) => {
+                let (kept,rest) = (whole / scale,whole % scale);
+                // the remainder of the division only matters when the whole digits dropped are zero or exactly half
+                let dropped = match (Dropped::of(rest,scale),remainder) {
+                    (None,0) => None,
+                    (None,_) => Some(Dropped::BelowHalf),
+                    (Some(Dropped::Half),remainder) if remainder != 0 => Some(Dropped::AboveHalf),
+                    (dropped,_) => dropped
+                };
+                (kept,dropped)
+            },
+            None => (0,(numer != 0).then_some(Dropped::BelowHalf))
+        }
+    };
+
+    match dropped {
+        Some(dropped) => mode.apply(negative,kept,dropped).map(Rounded::Kept),
+        None => Some(Rounded::Unchanged)
+    }
+}
+
+/// Holds `±kept * 10^-places` as an integer, given that `places` is negative.
+fn integer_result<T : FractionInteger>(negative : bool,kept : u128,places : i32) -> GenericNumber<T> {
+    let integer = 10_u128.checked_pow(places.unsigned_abs())
+        .and_then(|scale| kept.checked_mul(scale))
+        .and_then(|magnitude| signed_integer(negative,magnitude));
+
+    match integer {
+        Some(integer) => GenericNumber::Integer(integer),
+        None => wide_result(negative,kept,places)
+    }
+}
+
+/// Holds `±kept * 10^-places` as a fraction.
+fn fraction_result<T : FractionInteger>(negative : bool,kept : u128,places : i32) -> GenericNumber<T> {
+    let scale = 10_u128.checked_pow(places.unsigned_abs());
+    let ratio = match (places >= 0,scale) {
+        (true,Some(scale)) => Some(Ratio::new(kept,scale)),
+        (false,Some(scale)) => kept.checked_mul(scale).map(Ratio::from_integer),
+        (_,None) => None
+    };
+
+    let fraction = ratio.and_then(|ratio| Some((T::from_u128(*ratio.numer())?,T::from_u128(*ratio.denom())?)));
+    match fraction {
+        Some((numer,denom)) if negative => GenericNumber::Fraction(GenericFraction::new_neg(numer,denom)),
+        Some((numer,denom)) => GenericNumber::Fraction(GenericFraction::new(numer,denom)),
+        None => wide_result(negative,kept,places)
+    }
+}
+
+/// Holds `±kept * 10^-places` as a big fraction, for exact results that do not fit into their variant.
+#[cfg(feature="bigint")]
+fn wide_result<T : FractionInteger>(negative : bool,kept : u128,places : i32) -> GenericNumber<T> {
+    crate::bigint::narrow(crate::bigint::scaled_big_fraction(negative,kept,places))
+}
+
+/// Holds `±kept * 10^-places` as the nearest decimal, for exact results that do not fit into their variant.
+#[cfg(not(feature="bigint"))]
+fn wide_result<T : FractionInteger>(negative : bool,kept : u128,places : i32) -> GenericNumber<T> {
+    GenericNumber::Decimal(float_result(negative,kept,places))
+}
+
+/// Rounds a fraction whose numerator and denominator, or whose rounded digits, do not fit into a `u128`.
+#[cfg(feature="bigint")]
+fn round_wide_fraction<T : FractionInteger>(fr : &GenericFraction<T>,places : i32,mode : RoundingMode) -> GenericNumber<T> {
+    crate::bigint::narrow(crate::bigint::round_big_fraction(&crate::bigint::widen(fr),places,mode))
+}
+
+/// Rounds the nearest decimal to a fraction whose numerator and denominator, or whose rounded digits, do not fit into a `u128`.
+#[cfg(not(feature="bigint"))]
+fn round_wide_fraction<T : FractionInteger>(fr : &GenericFraction<T>,places : i32,mode : RoundingMode) -> GenericNumber<T> {
+    GenericNumber::<T>::Decimal(fraction_to_f64(fr)).round_to(places,mode)
+}
+
+/// Converts `±kept * 10^-places` into the nearest `f64`.
+fn float_result(negative : bool,kept : u128,places : i32) -> f64 {
+    let sign = match negative {
+        true => "-",
+        false => ""
+    };
+
+    format!("{sign}{kept}e{}",-(places as i64)).parse().expect("digits with an exponent form a valid float")
+}
+
+/// Holds `±kept * 10^-places` in standard form, or as the nearest decimal if its exponent is out of range.
+fn sf_result<T : FractionInteger>(negative : bool,kept : u128,places : i32) -> GenericNumber<T> {
+    let digits = kept.to_string();
+    let exponent = (digits.len() as i64 - 1 - places as i64).clamp(i32::MIN as i64,i32::MAX as i64) as i32;
+
+    let sf = match (kept,exponent) {
+        (0,_) => StandardForm::new(0.0,0),
+        // leaves room for rounding the mantissa up to the next exponent
+        (_,exponent) if (i8::MIN as i32..i8::MAX as i32).contains(&exponent) => digits_to_sf(&digits,exponent),
+        _ => return GenericNumber::Decimal(float_result(negative,kept,places))
+    };
+
+    GenericNumber::StandardForm(match negative {
+        true => -sf,
+        false => sf
+    })
+}
+
+/// Computes the fractional part of a finite number in standard form from the decimal it is written as.
+fn sf_fract(sf : &StandardForm) -> StandardForm {
+    if *sf.mantissa() == 0.0 {
+        return StandardForm::new(0.0,0);
+    }
+
+    let (significand,power) = sf_decimal(sf);
+    if power >= 0 {
+        return StandardForm::new(0.0,0);
+    }
+
+    let fractional = match 10_u128.checked_pow(power.unsigned_abs()) {
+        Some(scale) => significand % scale,
+        // the number is too small to have an integer part
+        None => return sf.clone()
+    };
+
+    let fract = match fractional {
+        0 => StandardForm::new(0.0,0),
+        fractional => digits_to_sf(&fractional.to_string(),fractional.ilog10() as i32 + power)
+    };
+
+    match sf.mantissa().is_sign_negative() {
+        true => -fract,
+        false => fract
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Number;
+
+    use super::*;
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let modes = [
+            (RoundingMode::HalfEven,[2,3,-2,-3]),
+            (RoundingMode::HalfUp,[3,3,-3,-3]),
+            (RoundingMode::HalfDown,[2,3,-2,-3]),
+            (RoundingMode::TowardZero,[2,2,-2,-2]),
+            (RoundingMode::AwayFromZero,[3,3,-3,-3]),
+            (RoundingMode::Floor,[2,2,-3,-3]),
+            (RoundingMode::Ceiling,[3,3,-2,-2]),
+        ];
+
+        // 2.5, 2.6, -2.5 and -2.6
+        let values = [fraction(5,2),fraction(13,5),-fraction(5,2),-fraction(13,5)];
+
+        for (mode,expected) in modes {
+            for (value,expected) in values.iter().zip(expected) {
+                let rounded = value.round_to(0,mode);
+                assert!(matches!(rounded,Number::Fraction(_)));
+                assert_eq!(rounded,expected,"{value} rounded with {mode:?}");
+
+                assert_eq!(Number::Decimal(value.clone().into()).round_to(0,mode),expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_to_decimal_places() {
+        let third = fraction(1,3);
+        assert!(third.round_to(4,RoundingMode::HalfEven).structural_eq(&fraction(3333,10000)));
+        assert!(third.round_to(4,RoundingMode::Ceiling).structural_eq(&fraction(3334,10000)));
+
+        // decimals are rounded as they are written, not as their binary value
+        assert!(Number::Decimal(2.675).round_to(2,RoundingMode::HalfUp).structural_eq(&Number::Decimal(2.68)));
+        assert!(Number::Decimal(-1.005).round_to(2,RoundingMode::HalfEven).structural_eq(&Number::Decimal(-1.0)));
+
+        let sf = Number::StandardForm(StandardForm::new(1.2345,3));
+        assert!(sf.round_to(0,RoundingMode::HalfEven).structural_eq(&Number::StandardForm(StandardForm::new(1.234,3))));
+        assert!(sf.round_to(-2,RoundingMode::HalfEven).structural_eq(&Number::StandardForm(StandardForm::new(1.2,3))));
+
+        assert!(Number::Integer(-1250).round_to(-2,RoundingMode::HalfEven).structural_eq(&Number::Integer(-1200)));
+        assert!(Number::Integer(7).round_to(3,RoundingMode::Ceiling).structural_eq(&Number::Integer(7)));
+    }
+
+    #[test]
+    fn test_round_to_significant_figures() {
+        assert!(Number::Integer(12350).round_to_significant_figures(3,RoundingMode::HalfEven).structural_eq(&Number::Integer(12400)));
+        assert!(fraction(2,3).round_to_significant_figures(2,RoundingMode::HalfEven).structural_eq(&fraction(67,100)));
+        assert!(Number::Decimal(0.0012345).round_to_significant_figures(2,RoundingMode::TowardZero).structural_eq(&Number::Decimal(0.0012)));
+
+        let rounded = Number::StandardForm(StandardForm::new(9.96,-5)).round_to_significant_figures(2,RoundingMode::HalfEven);
+        assert!(rounded.structural_eq(&Number::StandardForm(StandardForm::new(1.0,-4))));
+    }
+
+    #[test]
+    fn test_floor_ceil_round_trunc_fract() {
+        let value = -fraction(7,4);
+        assert!(value.floor().structural_eq(&-fraction(2,1)));
+        assert!(value.ceil().structural_eq(&-fraction(1,1)));
+        assert!(value.round().structural_eq(&-fraction(2,1)));
+        assert!(value.trunc().structural_eq(&-fraction(1,1)));
+        assert!(value.fract().structural_eq(&-fraction(3,4)));
+
+        assert!(Number::Decimal(-2.5).round().structural_eq(&Number::Decimal(-3.0)));
+        assert!(Number::Decimal(-2.5).fract().structural_eq(&Number::Decimal(-0.5)));
+        assert!(Number::StandardForm(StandardForm::new(1.25,1)).fract().structural_eq(&Number::StandardForm(StandardForm::new(5.0,-1))));
+        assert!(Number::Integer(-3).fract().structural_eq(&Number::Integer(0)));
+    }
+
+    #[test]
+    fn test_special_values_are_unchanged() {
+        assert!(Number::Decimal(f64::NAN).round().is_nan());
+        assert!(Number::Decimal(f64::NEG_INFINITY).floor().structural_eq(&Number::Decimal(f64::NEG_INFINITY)));
+        assert!(Number::Fraction(GenericFraction::infinity()).round_to_significant_figures(3,RoundingMode::HalfEven).is_infinite());
+        assert!(Number::Decimal(f64::INFINITY).fract().is_nan());
+    }
+
+    #[test]
+    fn test_rounded_fraction_that_does_not_fit() {
+        let rounded = fraction(1,3).round_to(12,RoundingMode::HalfEven);
+
+        #[cfg(not(feature="bigint"))]
+        assert!(rounded.structural_eq(&Number::Decimal(0.333333333333)));
+        #[cfg(feature="bigint")]
+        assert!(matches!(rounded,Number::BigFraction(_)));
+        assert_eq!(f64::from(rounded),0.333333333333);
+    }
+}
@@ -0,0 +1,169 @@
+use fraction::Zero;
+
+use crate::{GenericNumber,FractionInteger};
+
+impl<T : FractionInteger> GenericNumber<T> {
+    /// Returns `true` if the number is NaN.
+    pub fn is_nan(&self) -> bool {
+        match self {
+            GenericNumber::Integer(_) => false,
+            GenericNumber::Decimal(d) => d.is_nan(),
+            GenericNumber::StandardForm(sf) => sf.mantissa().is_nan(),
+            GenericNumber::Fraction(fr) => fr.is_nan(),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => fr.is_nan(),
+            GenericNumber::Symbolic(symbolic) => symbolic.coefficient().is_nan(),
+        }
+    }
+
+    /// Returns `true` if the number is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        match self {
+            GenericNumber::Integer(_) => false,
+            GenericNumber::Decimal(d) => d.is_infinite(),
+            GenericNumber::StandardForm(sf) => sf.mantissa().is_infinite(),
+            GenericNumber::Fraction(fr) => fr.is_infinite(),
+            #[cfg(feature="bigint")]
+            GenericNumber::BigFraction(fr) => fr.is_infinite(),
+            GenericNumber::Symbolic(symbolic) => symbolic.coefficient().is_infinite(),
+        }
+    }
+
+    /// Returns `true` if the number is neither infinite nor NaN.
+    pub fn is_finite(&self) -> bool {
+        !self.is_nan() && !self.is_infinite()
+    }
+}
+
+/// The binary arithmetic operations on `Number`.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub(crate) enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem
+}
+
+impl Operation {
+    pub(crate) fn apply(self,lhs : f64,rhs : f64) -> f64 {
+        match self {
+            Operation::Add => lhs + rhs,
+            Operation::Sub => lhs - rhs,
+            Operation::Mul => lhs * rhs,
+            Operation::Div => lhs / rhs,
+            Operation::Rem => lhs % rhs,
+        }
+    }
+}
+
+/// Computes the result of an operation involving NaN, an infinity or a division by zero,
+/// returning `None` if the operands are ordinary.
+///
+/// The results follow IEEE 754 for every variant: NaN is contagious, `∞ - ∞`, `0 * ∞`, `∞ / ∞`,
+/// `∞ % x` and `x % 0` are NaN, `x / 0` and products with an infinity take the sign of the product of
+/// the signs, `x / ∞` is a signed zero and `x % ∞` is `x`. The caller decides which variant holds the result.
+pub(crate) fn special_value<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>) -> Option<GenericNumber<T>> {
+    let divides_by_zero = matches!(operation,Operation::Div | Operation::Rem) && is_zero(rhs);
+
+    if lhs.is_finite() && rhs.is_finite() && !divides_by_zero {
+        return None;
+    }
+
+    // keeps exact fractions exact, as converting them to floats would round them
+    if operation == Operation::Rem && lhs.is_finite() && rhs.is_infinite() {
+        return Some(lhs.clone());
+    }
+
+    Some(GenericNumber::Decimal(operation.apply(lhs.clone().into(),rhs.clone().into())))
+}
+
+pub(crate) fn is_zero<T : FractionInteger>(number : &GenericNumber<T>) -> bool {
+    match number {
+        GenericNumber::Integer(i) => *i == 0,
+        GenericNumber::Decimal(d) => *d == 0.0,
+        GenericNumber::StandardForm(sf) => *sf.mantissa() == 0.0,
+        GenericNumber::Fraction(fr) => fr.is_zero(),
+        #[cfg(feature="bigint")]
+        GenericNumber::BigFraction(fr) => fr.is_zero(),
+        GenericNumber::Symbolic(symbolic) => is_zero(symbolic.coefficient()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fraction::GenericFraction;
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    fn infinities() -> [Number;3] {
+        [
+            Number::Decimal(f64::INFINITY),
+            Number::Fraction(GenericFraction::infinity()),
+            Number::Decimal(f64::INFINITY) * Number::StandardForm(StandardForm::new(2.0,0)),
+        ]
+    }
+
+    fn twos() -> [Number;3] {
+        [
+            Number::Decimal(2.0),
+            Number::StandardForm(StandardForm::new(2.0,0)),
+            Number::Fraction(GenericFraction::new(2u32,1u32)),
+        ]
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert!(Number::Decimal(f64::NAN).is_nan());
+        assert!(Number::Fraction(GenericFraction::nan()).is_nan());
+        assert!(Number::Fraction(GenericFraction::neg_infinity()).is_infinite());
+        assert!(Number::StandardForm(StandardForm::new(1.2,3)).is_finite());
+        assert!(!Number::Decimal(f64::INFINITY).is_finite());
+    }
+
+    #[test]
+    fn test_nan_is_contagious() {
+        for two in twos() {
+            assert!((two.clone() * Number::Fraction(GenericFraction::nan())).is_nan());
+            assert!((Number::Decimal(f64::NAN) - two).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_infinity_arithmetic() {
+        for infinity in infinities() {
+            for other in infinities() {
+                assert!((infinity.clone() - other.clone()).is_nan());
+                assert!((infinity.clone() / other.clone()).is_nan());
+                assert_eq!(infinity.clone() + other.clone(),f64::INFINITY);
+            }
+
+            for two in twos() {
+                assert_eq!(two.clone() - infinity.clone(),f64::NEG_INFINITY);
+                assert_eq!(-two.clone() * infinity.clone(),f64::NEG_INFINITY);
+                assert_eq!(two.clone() / -infinity.clone(),0.0);
+                assert!((infinity.clone() % two.clone()).is_nan());
+                assert_eq!(two.clone() % infinity.clone(),two);
+                assert!((two * Number::Decimal(0.0) * infinity.clone()).is_nan());
+            }
+        }
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        for two in twos() {
+            assert_eq!(two.clone() / Number::Fraction(GenericFraction::new(0u32,1u32)),f64::INFINITY);
+            assert_eq!(-two.clone() / 0_i32,f64::NEG_INFINITY);
+            assert!((two.clone() % Number::StandardForm(StandardForm::new(0.0,0))).is_nan());
+            assert!((two * 0.0_f64 / 0_i32).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_special_results_keep_fractions() {
+        let infinity = Number::Fraction(GenericFraction::infinity());
+        let result = Number::Fraction(GenericFraction::new(2u32,1u32)) - infinity.clone() * infinity;
+        assert!(result.structural_eq(&Number::Fraction(GenericFraction::neg_infinity())));
+    }
+}
@@ -0,0 +1,508 @@
+use std::f64::consts::{E,PI};
+use std::ops::{Mul,Div,Neg};
+
+use crate::{GenericNumber,FractionInteger,ArithmeticError,ParsingSymbolicError,RoundingMode,PromotionPolicy};
+use crate::number::is_exact;
+use crate::special::{is_zero,Operation};
+
+/// A number multiplied by powers of the constants `π` and `e`, written as `coefficient * π^pi * e^e` such as `2π/3`.
+///
+/// The constants are only evaluated on demand with `to_f64` or `evaluate`, so multiplying and dividing stay exact
+/// when the coefficients are integers or fractions: `2π/3` times `3/4` is `π/2`, and `π` over `π` is `1`.
+/// Adding or subtracting only keeps the constants symbolic when both numbers have the same powers of them,
+/// so `checked_add` and `checked_sub` return an `ArithmeticError` otherwise.
+///
+/// The operators `*` and `/` produce a `GenericNumber`, which holds the result as `GenericNumber::Symbolic`.
+/// Like the arithmetic of `GenericNumber`, a power that overflows or a division by zero evaluates the result into a decimal,
+/// while `checked_mul` and `checked_div` return an `ArithmeticError` instead.
+#[derive(Debug,Clone,PartialEq)]
+pub struct GenericSymbolic<T : FractionInteger> {
+    coefficient : GenericNumber<T>,
+    pi : i32,
+    e : i32
+}
+
+/// A multiple of `π` and `e` whose fractions have a `u32` numerator and denominator.
+pub type Symbolic = GenericSymbolic<u32>;
+
+impl<T : FractionInteger> GenericSymbolic<T> {
+    /// Creates `coefficient * π^pi * e^e`. A coefficient of exactly zero drops the constants.
+    pub fn new(coefficient : GenericNumber<T>,pi : i32,e : i32) -> Self {
+        match is_exact(&coefficient) && is_zero(&coefficient) {
+            true => Self { coefficient , pi : 0 , e : 0 },
+            false => Self { coefficient , pi , e }
+        }
+    }
+
+    /// Creates the constant `π`.
+    pub fn pi() -> Self {
+        Self::new(GenericNumber::Integer(1),1,0)
+    }
+
+    /// Creates the constant `e`.
+    pub fn e() -> Self {
+        Self::new(GenericNumber::Integer(1),0,1)
+    }
+
+    /// Retrieves the number the constants are multiplied by.
+    pub fn coefficient(&self) -> &GenericNumber<T> {
+        &self.coefficient
+    }
+
+    /// Retrieves the power of `π`.
+    pub fn pi_power(&self) -> i32 {
+        self.pi
+    }
+
+    /// Retrieves the power of `e`.
+    pub fn e_power(&self) -> i32 {
+        self.e
+    }
+
+    /// Returns whether the number has no constants, so its value is just its coefficient.
+    pub fn is_rational(&self) -> bool {
+        self.pi == 0 && self.e == 0
+    }
+
+    /// Evaluates the number as the nearest `f64`.
+    pub fn to_f64(&self) -> f64 {
+        f64::from(self.coefficient.clone()) * PI.powi(self.pi) * E.powi(self.e)
+    }
+
+    /// Evaluates the number to `figures` significant figures, rounding half to even.
+    ///
+    /// Numbers without constants return their coefficient unchanged, while the others become decimals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `figures` is zero.
+    pub fn evaluate(&self,figures : u32) -> GenericNumber<T> {
+        match self.is_rational() {
+            true => self.coefficient.clone(),
+            false => GenericNumber::Decimal(self.to_f64()).round_to_significant_figures(figures,RoundingMode::HalfEven)
+        }
+    }
+
+    /// Adds two numbers with the same powers of `π` and `e`, returning `ArithmeticError::PrecisionLoss`
+    /// if their powers differ and neither is zero, as the sum would have to be evaluated.
+    pub fn checked_add(&self,other : &Self) -> Result<Self,ArithmeticError> {
+        match (self.pi,self.e) == (other.pi,other.e) {
+            true => Ok(Self::new(self.coefficient.clone() + other.coefficient.clone(),self.pi,self.e)),
+            false if self.is_zero() => Ok(other.clone()),
+            false if other.is_zero() => Ok(self.clone()),
+            false => Err(ArithmeticError::PrecisionLoss)
+        }
+    }
+
+    /// Subtracts two numbers with the same powers of `π` and `e`, like `checked_add`.
+    pub fn checked_sub(&self,other : &Self) -> Result<Self,ArithmeticError> {
+        self.checked_add(&-other.clone())
+    }
+
+    /// Multiplies two numbers, failing with `ArithmeticError::Overflow` if a power of `π` or `e` does not fit into an `i32`.
+    pub fn checked_mul(&self,other : &Self) -> Result<Self,ArithmeticError> {
+        let pi = self.pi.checked_add(other.pi).ok_or(ArithmeticError::Overflow)?;
+        let e = self.e.checked_add(other.e).ok_or(ArithmeticError::Overflow)?;
+        Ok(Self::new(self.coefficient.clone() * other.coefficient.clone(),pi,e))
+    }
+
+    /// Divides the number by `other`, failing with `ArithmeticError::DivisionByZero` if `other` is zero
+    /// or `ArithmeticError::Overflow` if a power of `π` or `e` does not fit into an `i32`.
+    pub fn checked_div(&self,other : &Self) -> Result<Self,ArithmeticError> {
+        if is_zero(&other.coefficient) {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let pi = self.pi.checked_sub(other.pi).ok_or(ArithmeticError::Overflow)?;
+        let e = self.e.checked_sub(other.e).ok_or(ArithmeticError::Overflow)?;
+        Ok(Self::new(self.coefficient.clone() / other.coefficient.clone(),pi,e))
+    }
+
+    fn is_zero(&self) -> bool {
+        is_exact(&self.coefficient) && is_zero(&self.coefficient)
+    }
+}
+
+/// Writes a constant raised to a power, leaving it out for a power of zero.
+fn constant(symbol : &str,power : i32) -> String {
+    match power {
+        0 => String::new(),
+        1 => symbol.to_string(),
+        power => format!("{symbol}^{power}")
+    }
+}
+
+/// Parses a term such as `2π`, `3*e^2` or `-pi`, returning its coefficient and the powers of `π` and `e`.
+fn parse_term<T : FractionInteger>(term : &str) -> Result<(GenericNumber<T>,i32,i32),ParsingSymbolicError> {
+    let (rest,pi,e) = split_constants(term)?;
+    let coefficient = match rest {
+        "" | "+" if rest.len() < term.len() => GenericNumber::Integer(1),
+        "-" if rest.len() < term.len() => GenericNumber::Integer(-1),
+        "" => return Err(ParsingSymbolicError::Notation),
+        coefficient => GenericNumber::try_from(coefficient)?
+    };
+
+    Ok((coefficient,pi,e))
+}
+
+/// Takes the constants off the end of a term, returning the coefficient left in front of them and the powers of `π` and `e`.
+fn split_constants(term : &str) -> Result<(&str,i32,i32),ParsingSymbolicError> {
+    let (mut rest,mut pi,mut e) = (term,0,0);
+
+    // constants are taken off the end one at a time, so `2e3` stays a number while `2e` and `2e^3` are multiples of `e`
+    loop {
+        let (base,power) = match rest.rsplit_once('^').and_then(|(base,power)| Some((base,power.parse::<i32>().ok()?))) {
+            Some(split) => split,
+            None => (rest,1)
+        };
+
+        let is_pi = |base : &str| base.len() >= 2 && base.get(base.len() - 2..).is_some_and(|end| end.eq_ignore_ascii_case("pi"));
+        let add = |total : &mut i32| total.checked_add(power).map(|sum| *total = sum).ok_or(ParsingSymbolicError::Notation);
+        let base = if let Some(base) = base.strip_suffix('π') {
+            add(&mut pi)?;
+            base
+        } else if is_pi(base) {
+            add(&mut pi)?;
+            &base[..base.len() - 2]
+        } else if let Some(base) = base.strip_suffix('e') {
+            add(&mut e)?;
+            base
+        } else {
+            break;
+        };
+
+        rest = base.strip_suffix('*').unwrap_or(base);
+    }
+
+    Ok((rest,pi,e))
+}
+
+/// Parses a number holding powers of the constants for `GenericNumber`, returning `None` if it cannot be parsed.
+pub(crate) fn parse_symbolic<T : FractionInteger>(value : &str) -> Option<GenericNumber<T>> {
+    // a value without constants has already failed to parse as a number, and parsing it as a coefficient would come back here
+    let compact : String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    let (numer,denom) = compact.split_once('/').unwrap_or((&compact,""));
+    if ![numer,denom].into_iter().any(|term| split_constants(term).is_ok_and(|(rest,_,_)| rest.len() < term.len())) {
+        return None;
+    }
+
+    GenericSymbolic::try_from(value).ok().map(GenericNumber::from)
+}
+
+/// Returns `true` if the number holds powers of the constants.
+pub(crate) fn is_symbolic<T : FractionInteger>(number : &GenericNumber<T>) -> bool {
+    matches!(number,GenericNumber::Symbolic(_))
+}
+
+/// Evaluates a number holding powers of the constants into the nearest decimal, leaving other numbers as they are.
+pub(crate) fn evaluated<T : FractionInteger>(number : &GenericNumber<T>) -> GenericNumber<T> {
+    match number {
+        GenericNumber::Symbolic(symbolic) => GenericNumber::Decimal(symbolic.to_f64()),
+        number => number.clone()
+    }
+}
+
+/// Evaluates a number like `evaluated`, failing if the powers of the constants are too large or too small for an `f64`.
+pub(crate) fn checked_evaluated<T : FractionInteger>(number : &GenericNumber<T>) -> Result<GenericNumber<T>,ArithmeticError> {
+    match evaluated(number) {
+        GenericNumber::Decimal(d) if is_symbolic(number) && d.is_infinite() => Err(ArithmeticError::Overflow),
+        GenericNumber::Decimal(d) if is_symbolic(number) && d == 0.0 => Err(ArithmeticError::PrecisionLoss),
+        number => Ok(number)
+    }
+}
+
+/// Applies an operation to two numbers, at least one of which holds powers of the constants, keeping the constants symbolic.
+///
+/// Returns `None` if the result has to be evaluated instead: when either operand is not exact, the policy prefers
+/// decimals or standard form, a sum has different powers, a power overflows, or for a remainder or a division by zero.
+pub(crate) fn symbolic_operation<T : FractionInteger>(operation : Operation,lhs : &GenericNumber<T>,rhs : &GenericNumber<T>,policy : PromotionPolicy) -> Option<GenericNumber<T>> {
+    if matches!(policy,PromotionPolicy::PreferDecimal | PromotionPolicy::PreferStandardForm) {
+        return None;
+    }
+
+    let (lhs,rhs) = (exact_symbolic(lhs)?,exact_symbolic(rhs)?);
+    let result = match operation {
+        Operation::Add => lhs.checked_add(&rhs),
+        Operation::Sub => lhs.checked_sub(&rhs),
+        Operation::Mul => lhs.checked_mul(&rhs),
+        Operation::Div => lhs.checked_div(&rhs),
+        Operation::Rem => return None
+    };
+
+    result.ok().map(GenericNumber::from)
+}
+
+/// Converts a number into a symbolic one, returning `None` if its coefficient is not exact.
+fn exact_symbolic<T : FractionInteger>(number : &GenericNumber<T>) -> Option<GenericSymbolic<T>> {
+    match number {
+        GenericNumber::Symbolic(symbolic) => is_exact(symbolic.coefficient()).then(|| (**symbolic).clone()),
+        number => is_exact(number).then(|| GenericSymbolic::from(number.clone()))
+    }
+}
+
+impl<T : FractionInteger> From<GenericNumber<T>> for GenericSymbolic<T> {
+    fn from(value: GenericNumber<T>) -> Self {
+        match value {
+            GenericNumber::Symbolic(symbolic) => *symbolic,
+            value => Self::new(value,0,0)
+        }
+    }
+}
+
+/// A number without constants is held as its coefficient, and one whose coefficient is not exact is evaluated into a decimal.
+impl<T : FractionInteger> From<GenericSymbolic<T>> for GenericNumber<T> {
+    fn from(value: GenericSymbolic<T>) -> Self {
+        match (value.is_rational(),is_exact(&value.coefficient)) {
+            (true,_) => value.coefficient,
+            (false,true) => GenericNumber::Symbolic(Box::new(value)),
+            (false,false) => GenericNumber::Decimal(value.to_f64())
+        }
+    }
+}
+
+impl<T : FractionInteger> From<GenericSymbolic<T>> for f64 {
+    fn from(value: GenericSymbolic<T>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl<T : FractionInteger> std::fmt::Display for GenericSymbolic<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let constants = constant("π",self.pi) + &constant("e",self.e);
+        if constants.is_empty() {
+            return write!(f,"{}",self.coefficient);
+        }
+
+        match &self.coefficient {
+            GenericNumber::Decimal(_) => write!(f,"{}{constants}",self.coefficient),
+            GenericNumber::StandardForm(_) => write!(f,"{}*{constants}",self.coefficient),
+            // the constants go between the numerator and the denominator of exact coefficients
+            coefficient => {
+                let text = coefficient.to_string();
+                let (numer,denom) = text.split_once('/').map_or((text.as_str(),None),|(numer,denom)| (numer,Some(denom)));
+                match numer {
+                    "1" => write!(f,"{constants}")?,
+                    "-1" => write!(f,"-{constants}")?,
+                    numer => write!(f,"{numer}{constants}")?
+                }
+
+                match denom {
+                    Some(denom) => write!(f,"/{denom}"),
+                    None => Ok(())
+                }
+            }
+        }
+    }
+}
+
+impl<T : FractionInteger> TryFrom<&str> for GenericSymbolic<T> {
+    type Error = ParsingSymbolicError;
+
+    /// Parses a coefficient followed by the constants `π` (or `pi`) and `e`, each optionally raised to an integer power
+    /// with `^`, over an optional denominator, such as `2π/3`, `2pi/3`, `-e^2` or `3/4`. The coefficient may be left out
+    /// or joined to the constants by `*`, and the denominator may hold constants too, so `1/π` is `π^-1`.
+    /// A denominator of zero, such as in `1/0π`, and powers that do not fit into an `i32` are rejected.
+    fn try_from(value : &str) -> Result<Self, Self::Error> {
+        let compact : String = value.chars().filter(|c| !c.is_whitespace()).collect();
+        let (numer,denom) = match compact.split_once('/') {
+            Some((numer,denom)) => (numer,Some(denom)),
+            None => (compact.as_str(),None)
+        };
+
+        let (coefficient,pi,e) = parse_term(numer)?;
+        match denom {
+            Some(denom) => {
+                let (denom,denom_pi,denom_e) = parse_term(denom)?;
+                Self::new(coefficient,pi,e).checked_div(&Self::new(denom,denom_pi,denom_e)).map_err(|_| ParsingSymbolicError::Notation)
+            },
+            None => Ok(Self::new(coefficient,pi,e))
+        }
+    }
+}
+
+impl<T : FractionInteger> Neg for GenericSymbolic<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.coefficient,self.pi,self.e)
+    }
+}
+
+impl<T : FractionInteger> Mul for GenericSymbolic<T> {
+    type Output = GenericNumber<T>;
+
+    fn mul(self, other: Self) -> GenericNumber<T> {
+        GenericNumber::from(self) * GenericNumber::from(other)
+    }
+}
+
+impl<T : FractionInteger> Div for GenericSymbolic<T> {
+    type Output = GenericNumber<T>;
+
+    fn div(self, other: Self) -> GenericNumber<T> {
+        GenericNumber::from(self) / GenericNumber::from(other)
+    }
+}
+
+impl<T : FractionInteger> Mul<GenericNumber<T>> for GenericSymbolic<T> {
+    type Output = GenericNumber<T>;
+
+    fn mul(self, other: GenericNumber<T>) -> GenericNumber<T> {
+        GenericNumber::from(self) * other
+    }
+}
+
+impl<T : FractionInteger> Div<GenericNumber<T>> for GenericSymbolic<T> {
+    type Output = GenericNumber<T>;
+
+    fn div(self, other: GenericNumber<T>) -> GenericNumber<T> {
+        GenericNumber::from(self) / other
+    }
+}
+
+impl<T : FractionInteger> Mul<GenericSymbolic<T>> for GenericNumber<T> {
+    type Output = GenericNumber<T>;
+
+    fn mul(self, other: GenericSymbolic<T>) -> GenericNumber<T> {
+        self * GenericNumber::from(other)
+    }
+}
+
+impl<T : FractionInteger> Div<GenericSymbolic<T>> for GenericNumber<T> {
+    type Output = GenericNumber<T>;
+
+    fn div(self, other: GenericSymbolic<T>) -> GenericNumber<T> {
+        self / GenericNumber::from(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fraction::GenericFraction;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_parsing() {
+        let two_thirds_pi = Symbolic::new(fraction(2,3),1,0);
+        assert_eq!(Symbolic::try_from("2pi/3").unwrap(),two_thirds_pi);
+        assert_eq!(Symbolic::try_from("2π/3").unwrap(),two_thirds_pi);
+        assert_eq!(Symbolic::try_from("2 * PI / 3").unwrap(),two_thirds_pi);
+        assert_eq!(Symbolic::try_from("-π").unwrap(),-Symbolic::pi());
+        assert_eq!(Symbolic::try_from("3e^2").unwrap(),Symbolic::new(Number::Integer(3),0,2));
+        assert_eq!(Symbolic::try_from("1/π").unwrap(),Symbolic::new(Number::Integer(1),-1,0));
+        assert_eq!(Symbolic::try_from("πe").unwrap(),Symbolic::from(Symbolic::pi() * Symbolic::e()));
+
+        // numbers without constants are parsed as usual
+        assert_eq!(Symbolic::try_from("2e3").unwrap(),Symbolic::from(Number::Integer(2000)));
+        assert_eq!(Symbolic::try_from("1.2*10^3").unwrap(),Symbolic::from(Number::Integer(1200)));
+        assert!(Symbolic::try_from("3/4").unwrap().coefficient().structural_eq(&fraction(3,4)));
+
+        assert!(matches!(Symbolic::try_from(""),Err(ParsingSymbolicError::Notation)));
+        assert!(matches!(Symbolic::try_from("π/"),Err(ParsingSymbolicError::Notation)));
+        assert!(matches!(Symbolic::try_from("1/0π"),Err(ParsingSymbolicError::Notation)));
+        assert!(matches!(Symbolic::try_from("π/0"),Err(ParsingSymbolicError::Notation)));
+        assert!(matches!(Symbolic::try_from("π^2147483647*π"),Err(ParsingSymbolicError::Notation)));
+        assert!(matches!(Symbolic::try_from("π^2147483647/π^-1"),Err(ParsingSymbolicError::Notation)));
+        assert!(matches!(Symbolic::try_from("xπ"),Err(ParsingSymbolicError::Number(_))));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Symbolic::new(fraction(2,3),1,0).to_string(),"2π/3");
+        assert_eq!(Symbolic::new(fraction(1,2),1,0).to_string(),"π/2");
+        assert_eq!((-Symbolic::pi()).to_string(),"-π");
+        assert_eq!(Symbolic::new(Number::Integer(3),2,1).to_string(),"3π^2e");
+        assert_eq!(Symbolic::new(Number::Decimal(0.5),0,-1).to_string(),"0.5e^-1");
+        assert_eq!(Symbolic::new(Number::Integer(0),1,0).to_string(),"0");
+
+        let two_thirds_pi = Symbolic::new(fraction(2,3),1,0);
+        assert_eq!(Symbolic::try_from(two_thirds_pi.to_string().as_str()).unwrap(),two_thirds_pi);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let two_thirds_pi = Symbolic::try_from("2π/3").unwrap();
+        let half_pi = Symbolic::from(two_thirds_pi.clone() * fraction(3,4));
+        assert!(half_pi.coefficient().structural_eq(&fraction(1,2)));
+        assert_eq!(half_pi.pi_power(),1);
+
+        // constants that cancel out leave just the coefficient
+        assert!((half_pi.clone() / Symbolic::pi()).structural_eq(&fraction(1,2)));
+        assert_eq!(Symbolic::new(fraction(1,2),0,0).evaluate(5),fraction(1,2));
+
+        assert_eq!(half_pi.checked_add(&half_pi).unwrap(),Symbolic::pi());
+        assert_eq!(half_pi.checked_sub(&half_pi).unwrap(),Symbolic::from(Number::Integer(0)));
+        assert_eq!(half_pi.checked_add(&Symbolic::e()),Err(ArithmeticError::PrecisionLoss));
+        assert_eq!(Symbolic::from(Number::Integer(0)).checked_add(&Symbolic::e()).unwrap(),Symbolic::e());
+
+        assert_eq!(Symbolic::from(Number::Integer(2) / Symbolic::e()).e_power(),-1);
+    }
+
+    #[test]
+    fn test_operators_evaluate_instead_of_failing() {
+        let huge = Symbolic::new(Number::Integer(1),i32::MAX,0);
+        assert!((huge.clone() * Symbolic::pi()).structural_eq(&Number::Decimal(f64::INFINITY)));
+        assert!((huge.clone() / Symbolic::new(Number::Integer(1),-1,0)).structural_eq(&Number::Decimal(f64::INFINITY)));
+        assert!((Symbolic::pi() / Number::Integer(0)).structural_eq(&Number::Decimal(f64::INFINITY)));
+        assert!((-Symbolic::e() / Number::Integer(0)).structural_eq(&Number::Decimal(f64::NEG_INFINITY)));
+
+        let number = Number::from(huge);
+        assert_eq!(number.checked_mul(&Symbolic::pi().into()),Err(ArithmeticError::Overflow));
+        assert_eq!(Number::from(Symbolic::pi()).checked_div(&Number::Integer(0)),Err(ArithmeticError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_number() {
+        let two_thirds_pi = Number::try_from("2pi/3").unwrap();
+        assert!(two_thirds_pi.structural_eq(&Symbolic::new(fraction(2,3),1,0).into()));
+        assert!(two_thirds_pi.structural_eq(&Number::try_from("2π/3").unwrap()));
+        assert_eq!(two_thirds_pi.to_string(),"2π/3");
+
+        let half_pi = two_thirds_pi * fraction(3,4);
+        assert!(half_pi.structural_eq(&Symbolic::new(fraction(1,2),1,0).into()));
+        assert!((half_pi.clone() + half_pi.clone()).structural_eq(&Symbolic::new(fraction(1,1),1,0).into()));
+        assert!((half_pi.clone() - half_pi.clone()).structural_eq(&fraction(0,1)));
+        assert!((half_pi.clone() / Number::from(Symbolic::pi())).structural_eq(&fraction(1,2)));
+        assert!(half_pi.powi(2).structural_eq(&Symbolic::new(fraction(1,4),2,0).into()));
+        assert!((-half_pi.clone()).structural_eq(&Symbolic::new(-fraction(1,2),1,0).into()));
+
+        // arithmetic that cannot keep the constants evaluates them
+        let half = std::f64::consts::FRAC_PI_2;
+        assert!((half_pi.clone() + Number::Integer(1)).structural_eq(&Number::Decimal(half + 1.0)));
+        assert!((half_pi.clone() * Number::Decimal(2.0)).structural_eq(&Number::Decimal(PI)));
+        assert!(half_pi.mul_with(&Number::Integer(2),PromotionPolicy::PreferDecimal).structural_eq(&Number::Decimal(PI)));
+
+        assert_eq!(half_pi,Number::Decimal(half));
+        assert!(half_pi > Number::Integer(1) && half_pi < fraction(8,5));
+        assert_eq!(half_pi.to_f64_exact().unwrap_err().approximation(),half);
+        assert!(half_pi.round_to(2,RoundingMode::HalfEven).structural_eq(&Number::Decimal(1.57)));
+
+        assert!(Number::try_from("ex").is_err());
+        assert!(Number::try_from("2pi/0").is_err());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let huge = Symbolic::new(Number::Integer(1),i32::MAX,0);
+        assert_eq!(huge.checked_mul(&Symbolic::pi()),Err(ArithmeticError::Overflow));
+        assert_eq!(huge.checked_div(&Symbolic::new(Number::Integer(1),-1,0)),Err(ArithmeticError::Overflow));
+        assert_eq!(Symbolic::new(Number::Integer(1),0,i32::MIN).checked_div(&Symbolic::e()),Err(ArithmeticError::Overflow));
+        assert_eq!(Symbolic::pi().checked_div(&Symbolic::from(Number::Integer(0))),Err(ArithmeticError::DivisionByZero));
+        assert_eq!(huge.checked_div(&Symbolic::pi()).unwrap().pi_power(),i32::MAX - 1);
+    }
+
+    #[test]
+    fn test_evaluation() {
+        assert_eq!(Symbolic::new(fraction(2,3),1,0).to_f64(),2.0 / 3.0 * PI);
+        assert_eq!(f64::from(Symbolic::e()),E);
+        assert!(Symbolic::pi().evaluate(2).structural_eq(&Number::Decimal(3.1)));
+        assert!(Symbolic::new(Number::Integer(1),1,1).evaluate(4).structural_eq(&Number::Decimal(8.540)));
+    }
+}
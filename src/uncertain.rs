@@ -0,0 +1,318 @@
+use std::ops::{Add,Sub,Mul,Div,Neg};
+
+use crate::{GenericNumber,FractionInteger,ParsingUncertainError,RoundingMode};
+use crate::round::leading_exponent;
+
+#[cfg(feature="num")]
+use num_traits::Pow;
+
+/// A number with a standard uncertainty, which arithmetic propagates to first order.
+///
+/// The operands of arithmetic are taken to be independent, so their contributions to the uncertainty of the result
+/// are added in quadrature. The central value is computed like arithmetic on `Number`, so it stays exact where possible,
+/// while the uncertainty is an estimate held as an `f64`. Plain numbers in arithmetic with an uncertain number are exact.
+///
+/// `Display` writes the uncertainty after `±`, and the alternate flag `{:#}` writes it in parentheses as the digits it
+/// adds to the last digits of the value, so `1.230 ± 0.040` is `1.230(40)`. Both round the uncertainty to two significant figures
+/// and the value to the same decimal place.
+#[derive(Debug,Clone)]
+pub struct GenericUncertain<T : FractionInteger> {
+    value : GenericNumber<T>,
+    uncertainty : f64
+}
+
+/// A number with an uncertainty whose fractions have a `u32` numerator and denominator.
+pub type Uncertain = GenericUncertain<u32>;
+
+impl<T : FractionInteger> GenericUncertain<T> {
+    /// Creates a number with a standard uncertainty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uncertainty` is negative or NaN.
+    pub fn new(value : GenericNumber<T>,uncertainty : f64) -> Self {
+        assert!(uncertainty >= 0.0,"the uncertainty must not be negative or NaN");
+        Self { value , uncertainty }
+    }
+
+    /// Retrieves the central value.
+    pub fn value(&self) -> &GenericNumber<T> {
+        &self.value
+    }
+
+    /// Retrieves the standard uncertainty.
+    pub fn uncertainty(&self) -> f64 {
+        self.uncertainty
+    }
+
+    /// Returns the uncertainty relative to the magnitude of the central value.
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty / f64::from(self.value.clone()).abs()
+    }
+
+    /// Applies a function of one variable to the central value, propagating the uncertainty through its derivative.
+    pub(crate) fn propagate(self,function : impl Fn(f64) -> f64,derivative : impl Fn(f64) -> f64) -> Self {
+        let x = f64::from(self.value);
+        Self { value : GenericNumber::Decimal(function(x)) , uncertainty : contribution(derivative(x),self.uncertainty) }
+    }
+
+    /// Returns the decimal place to write the number to, or `None` if the uncertainty is zero or either part is not finite.
+    fn decimal_places(&self) -> Option<i32> {
+        if !self.value.is_finite() || !self.uncertainty.is_finite() {
+            return None;
+        }
+
+        let rounded = GenericNumber::<T>::Decimal(self.uncertainty).round_to_significant_figures(2,RoundingMode::HalfEven);
+        leading_exponent(&rounded).map(|exponent| 1 - exponent)
+    }
+}
+
+/// The contribution of an operand to the uncertainty of a result, given the derivative of the result with respect to it.
+fn contribution(derivative : f64,uncertainty : f64) -> f64 {
+    // exact operands contribute nothing, even where the derivative is infinite
+    match uncertainty == 0.0 {
+        true => 0.0,
+        false => (derivative * uncertainty).abs()
+    }
+}
+
+impl<T : FractionInteger> From<GenericNumber<T>> for GenericUncertain<T> {
+    fn from(value: GenericNumber<T>) -> Self {
+        Self { value , uncertainty : 0.0 }
+    }
+}
+
+impl<T : FractionInteger> std::fmt::Display for GenericUncertain<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Some(decimal_places) = self.decimal_places() else {
+            return match f.alternate() {
+                true => write!(f,"{}({})",self.value,self.uncertainty),
+                false => write!(f,"{} ± {}",self.value,self.uncertainty)
+            };
+        };
+
+        let value = f64::from(self.value.round_to(decimal_places,RoundingMode::HalfEven));
+        let places = decimal_places.max(0) as usize;
+        let uncertainty = f64::from(GenericNumber::<T>::Decimal(self.uncertainty).round_to(decimal_places,RoundingMode::HalfEven));
+
+        match f.alternate() {
+            true => write!(f,"{value:.places$}({:.0})",uncertainty * 10_f64.powi(places as i32)),
+            false => write!(f,"{value:.places$} ± {uncertainty:.places$}")
+        }
+    }
+}
+
+impl<T : FractionInteger> TryFrom<&str> for GenericUncertain<T> {
+    type Error = ParsingUncertainError;
+
+    /// Parses `1.23 ± 0.04`, where either part can be any number, or `1.23(4)`, where the value must be written
+    /// as a plain decimal and the digits in parentheses are the uncertainty in its last digits.
+    fn try_from(value : &str) -> Result<Self, Self::Error> {
+        let (value,uncertainty) = match value.split_once('±') {
+            Some((value,uncertainty)) => (value.trim(),f64::from(GenericNumber::<T>::try_from(uncertainty.trim())?)),
+            None => {
+                let (value,digits) = value.trim().strip_suffix(')').and_then(|value| value.split_once('(')).ok_or(ParsingUncertainError::Notation)?;
+
+                let plain_decimal = value.trim_start_matches('-').bytes().all(|byte| byte.is_ascii_digit() || byte == b'.');
+                if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) || !plain_decimal {
+                    return Err(ParsingUncertainError::Notation);
+                }
+
+                let decimals = value.split_once('.').map_or(0,|(_,decimals)| decimals.len());
+                (value,format!("{digits}e-{decimals}").parse().expect("digits with an exponent form a valid float"))
+            }
+        };
+
+        match uncertainty >= 0.0 {
+            true => Ok(Self::new(GenericNumber::try_from(value)?,uncertainty)),
+            false => Err(ParsingUncertainError::InvalidUncertainty)
+        }
+    }
+}
+
+impl<T : FractionInteger> Neg for GenericUncertain<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { value : -self.value , uncertainty : self.uncertainty }
+    }
+}
+
+impl<T : FractionInteger> Add for GenericUncertain<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { value : self.value + other.value , uncertainty : self.uncertainty.hypot(other.uncertainty) }
+    }
+}
+
+impl<T : FractionInteger> Sub for GenericUncertain<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self { value : self.value - other.value , uncertainty : self.uncertainty.hypot(other.uncertainty) }
+    }
+}
+
+impl<T : FractionInteger> Mul for GenericUncertain<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let (x,y) = (f64::from(self.value.clone()),f64::from(other.value.clone()));
+        let uncertainty = contribution(y,self.uncertainty).hypot(contribution(x,other.uncertainty));
+        Self { value : self.value * other.value , uncertainty }
+    }
+}
+
+impl<T : FractionInteger> Div for GenericUncertain<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let (x,y) = (f64::from(self.value.clone()),f64::from(other.value.clone()));
+        let uncertainty = contribution(1.0 / y,self.uncertainty).hypot(contribution(x / (y * y),other.uncertainty));
+        Self { value : self.value / other.value , uncertainty }
+    }
+}
+
+#[cfg(feature="num")]
+impl<T : FractionInteger> Pow<GenericUncertain<T>> for GenericUncertain<T> {
+    type Output = Self;
+
+    fn pow(self, other: Self) -> Self {
+        let (x,y) = (f64::from(self.value.clone()),f64::from(other.value.clone()));
+        let uncertainty = contribution(y * x.powf(y - 1.0),self.uncertainty).hypot(contribution(x.powf(y) * x.ln(),other.uncertainty));
+        Self { value : self.value.pow(other.value) , uncertainty }
+    }
+}
+
+macro_rules! exact_operand {
+    ($($trait : ident => $fn : ident),*) => {
+        $(
+            impl<T : FractionInteger> $trait<GenericNumber<T>> for GenericUncertain<T> {
+                type Output = Self;
+
+                fn $fn(self, other: GenericNumber<T>) -> Self {
+                    self.$fn(GenericUncertain::from(other))
+                }
+            }
+
+            impl<T : FractionInteger> $trait<GenericUncertain<T>> for GenericNumber<T> {
+                type Output = GenericUncertain<T>;
+
+                fn $fn(self, other: GenericUncertain<T>) -> GenericUncertain<T> {
+                    GenericUncertain::from(self).$fn(other)
+                }
+            }
+        )*
+    };
+}
+
+exact_operand!(
+    Add => add,
+    Sub => sub,
+    Mul => mul,
+    Div => div
+);
+
+#[cfg(feature="num")]
+exact_operand!(Pow => pow);
+
+#[cfg(test)]
+mod tests {
+    use fraction::GenericFraction;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn assert_close(actual : f64,expected : f64) {
+        assert!((actual - expected).abs() <= 1e-12 * expected.abs(),"{actual} is not {expected}");
+    }
+
+    #[test]
+    fn test_sums_add_in_quadrature() {
+        let sum = Uncertain::new(Number::Decimal(1.0),0.3) + Uncertain::new(Number::Decimal(2.0),0.4);
+        assert_eq!(*sum.value(),3.0);
+        assert_close(sum.uncertainty(),0.5);
+
+        let difference = Uncertain::new(Number::Decimal(1.0),0.3) - Uncertain::new(Number::Decimal(2.0),0.4);
+        assert_close(difference.uncertainty(),0.5);
+    }
+
+    #[test]
+    fn test_products_add_relative_uncertainties_in_quadrature() {
+        let product = Uncertain::new(Number::Decimal(10.0),0.3) * Uncertain::new(Number::Decimal(20.0),0.8);
+        assert_eq!(*product.value(),200.0);
+        assert_close(product.relative_uncertainty(),0.05);
+
+        let quotient = Uncertain::new(Number::Decimal(10.0),0.3) / Uncertain::new(Number::Decimal(20.0),0.8);
+        assert_eq!(*quotient.value(),0.5);
+        assert_close(quotient.relative_uncertainty(),0.05);
+    }
+
+    #[test]
+    fn test_exact_operands() {
+        let third = Number::Fraction(GenericFraction::new(1u32,3u32));
+        let scaled = Uncertain::new(Number::Integer(3),0.1) * third;
+        assert!(scaled.value().structural_eq(&Number::Fraction(GenericFraction::new(1u32,1u32))));
+        assert_close(scaled.uncertainty(),0.1 / 3.0);
+
+        let shifted = Number::Integer(1) - Uncertain::new(Number::Integer(3),0.1);
+        assert!(shifted.value().structural_eq(&Number::Integer(-2)));
+        assert_close(shifted.uncertainty(),0.1);
+    }
+
+    #[test]
+    fn test_functions_propagate_through_derivatives() {
+        let angle = Uncertain::new(Number::Decimal(0.0),0.01);
+        assert_close(angle.clone().sin().uncertainty(),0.01);
+        assert_eq!(angle.cos().uncertainty(),0.0);
+
+        let exact = Uncertain::from(Number::Decimal(1.0)).asin();
+        assert_eq!(exact.uncertainty(),0.0);
+    }
+
+    #[cfg(feature="num")]
+    #[test]
+    fn test_pow() {
+        let squared = Uncertain::new(Number::Decimal(3.0),0.1).pow(Number::Decimal(2.0));
+        assert_eq!(*squared.value(),9.0);
+        assert_close(squared.uncertainty(),0.6);
+    }
+
+    #[test]
+    fn test_parse() {
+        let concise = Uncertain::try_from("1.23(4)").unwrap();
+        assert_eq!(*concise.value(),1.23);
+        assert_close(concise.uncertainty(),0.04);
+
+        let whole = Uncertain::try_from("-1234(56)").unwrap();
+        assert!(whole.value().structural_eq(&Number::Integer(-1234)));
+        assert_eq!(whole.uncertainty(),56.0);
+
+        let plus_minus = Uncertain::try_from("1/3 ± 0.02").unwrap();
+        assert!(plus_minus.value().structural_eq(&Number::Fraction(GenericFraction::new(1u32,3u32))));
+        assert_eq!(plus_minus.uncertainty(),0.02);
+
+        assert!(matches!(Uncertain::try_from("1.23"),Err(ParsingUncertainError::Notation)));
+        assert!(matches!(Uncertain::try_from("1e3(4)"),Err(ParsingUncertainError::Notation)));
+        assert!(matches!(Uncertain::try_from("1.23 ± -1"),Err(ParsingUncertainError::InvalidUncertainty)));
+        assert!(matches!(Uncertain::try_from("x ± 1"),Err(ParsingUncertainError::Number(_))));
+    }
+
+    #[test]
+    fn test_display() {
+        let number = Uncertain::new(Number::Decimal(1.23456),0.0432);
+        assert_eq!(number.to_string(),"1.235 ± 0.043");
+        assert_eq!(format!("{number:#}"),"1.235(43)");
+
+        let large = Uncertain::new(Number::Integer(123456),789.0);
+        assert_eq!(large.to_string(),"123460 ± 790");
+        assert_eq!(format!("{large:#}"),"123460(790)");
+
+        // the concise form is read back as the same number
+        let parsed = Uncertain::try_from(format!("{number:#}").as_str()).unwrap();
+        assert_eq!(*parsed.value(),1.235);
+        assert_close(parsed.uncertainty(),0.043);
+    }
+}
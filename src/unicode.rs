@@ -0,0 +1,128 @@
+use crate::{GenericNumber,FractionInteger};
+
+/// The Unicode vulgar fractions, such as `¾`, with their numerators and denominators.
+const VULGAR_FRACTIONS : [(char,u8,u8);19] = [
+    ('½',1,2),('⅓',1,3),('⅔',2,3),('¼',1,4),('¾',3,4),('⅕',1,5),('⅖',2,5),('⅗',3,5),('⅘',4,5),('⅙',1,6),
+    ('⅚',5,6),('⅐',1,7),('⅛',1,8),('⅜',3,8),('⅝',5,8),('⅞',7,8),('⅑',1,9),('⅒',1,10),('↉',0,3)
+];
+
+/// The fraction slash, as in `3⁄4`.
+pub(crate) const FRACTION_SLASH : char = '\u{2044}';
+
+/// The signs written between the mantissa and the power of ten in standard form, as in `1.2×10⁻³`.
+pub(crate) const MULTIPLICATION_SIGNS : [char;3] = ['*','×','·'];
+
+/// Returns the numerator and denominator of a Unicode vulgar fraction such as `¾`.
+pub(crate) fn vulgar_fraction(c : char) -> Option<(u8,u8)> {
+    VULGAR_FRACTIONS.iter().find(|(vulgar,_,_)| *vulgar == c).map(|&(_,numer,denom)| (numer,denom))
+}
+
+/// Returns the value of a superscript digit such as `³`.
+pub(crate) fn superscript_digit(c : char) -> Option<u32> {
+    match c {
+        '⁰' => Some(0),
+        '¹' => Some(1),
+        '²' => Some(2),
+        '³' => Some(3),
+        '⁴'..='⁹' => Some(c as u32 - '⁴' as u32 + 4),
+        _ => None
+    }
+}
+
+/// Returns the ASCII sign of a superscript sign, `⁺` or `⁻`.
+pub(crate) fn superscript_sign(c : char) -> Option<char> {
+    match c {
+        '⁺' => Some('+'),
+        '⁻' => Some('-'),
+        _ => None
+    }
+}
+
+/// Rewrites Unicode notation as the ASCII notation the other parsers read, returning `None` if there is none.
+///
+/// A lone vulgar fraction, optionally signed, becomes `numer/denom`, the fraction slash becomes `/`, `×` and `·` become `*`,
+/// and a run of superscript digits and signs becomes an exponent after `^`, so `1.2×10⁻³` becomes `1.2*10^-3`.
+fn to_ascii(value : &str) -> Option<String> {
+    let (sign,rest) = match value.strip_prefix('-') {
+        Some(rest) => ("-",rest),
+        None => ("",value.strip_prefix('+').unwrap_or(value))
+    };
+
+    let mut chars = rest.chars();
+    if let (Some((numer,denom)),None) = (chars.next().and_then(vulgar_fraction),chars.next()) {
+        return Some(format!("{sign}{numer}/{denom}"));
+    }
+
+    let mut ascii = String::with_capacity(value.len());
+    let mut superscript = false;
+    for c in value.chars() {
+        let exponent = superscript_sign(c).or_else(|| superscript_digit(c).and_then(|digit| char::from_digit(digit,10)));
+        match exponent {
+            Some(exponent) => {
+                if !superscript && !ascii.ends_with('^') {
+                    ascii.push('^');
+                }
+                ascii.push(exponent);
+            },
+            None if c == FRACTION_SLASH => ascii.push('/'),
+            None if MULTIPLICATION_SIGNS.contains(&c) => ascii.push('*'),
+            None => ascii.push(c)
+        }
+        superscript = exponent.is_some();
+    }
+
+    (ascii != value).then_some(ascii)
+}
+
+/// Parses a number written with Unicode notation, such as `½`, `3⁄4` or `1.2×10⁻³`, by rewriting it in ASCII.
+pub(crate) fn parse_unicode<T : FractionInteger>(value : &str) -> Option<GenericNumber<T>> {
+    GenericNumber::try_from(to_ascii(value)?.as_str()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use fraction::GenericFraction;
+    use standardform::StandardForm;
+
+    use crate::Number;
+
+    use super::*;
+
+    fn fraction(numer : u32,denom : u32) -> Number {
+        Number::Fraction(GenericFraction::new(numer,denom))
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert!(Number::try_from("½").unwrap().structural_eq(&fraction(1,2)));
+        assert!(Number::try_from("⅞").unwrap().structural_eq(&fraction(7,8)));
+        assert!(Number::try_from("-¼").unwrap().structural_eq(&-fraction(1,4)));
+        assert!(Number::try_from("+½").unwrap().structural_eq(&fraction(1,2)));
+        assert!(Number::try_from("+3⁄4").unwrap().structural_eq(&fraction(3,4)));
+        assert!(Number::try_from("+2¾").unwrap().structural_eq(&fraction(11,4)));
+        assert!(Number::try_from("+-½").is_err());
+        assert!(Number::try_from("3⁄4").unwrap().structural_eq(&fraction(3,4)));
+        assert!(Number::try_from("2 3⁄4").unwrap().structural_eq(&fraction(11,4)));
+        assert!(Number::try_from("½½").is_err());
+    }
+
+    #[test]
+    fn test_standard_form() {
+        let expected = Number::StandardForm(StandardForm::new(1.2,-3));
+        assert!(Number::try_from("1.2×10⁻³").unwrap().structural_eq(&expected));
+        assert!(Number::try_from("1.2·10⁻³").unwrap().structural_eq(&expected));
+        assert!(Number::try_from("1.2*10⁻³").unwrap().structural_eq(&expected));
+        assert!(Number::try_from("1.2×10^-3").unwrap().structural_eq(&expected));
+        assert!(Number::try_from("4×10¹²").unwrap().structural_eq(&Number::StandardForm(StandardForm::new(4.0,12))));
+        assert!(Number::try_from("6.02×10⁺²³").unwrap().structural_eq(&Number::StandardForm(StandardForm::new(6.02,23))));
+        assert!(Number::try_from("1.2×10⁻").is_err());
+        assert!(Number::try_from("2³").is_err());
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(to_ascii("1.2×10⁻³").as_deref(),Some("1.2*10^-3"));
+        assert_eq!(to_ascii("-⅔").as_deref(),Some("-2/3"));
+        assert_eq!(to_ascii("1.5"),None);
+    }
+}